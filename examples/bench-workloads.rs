@@ -25,7 +25,7 @@ fn example(stop_watch: &mut StopWatch, config: Config, work: u64) -> Result<(),
     // perform potentially lengthy preparation that will not reflect in the measurement
     // ...
     // fetch the workload definition from configuration associated with the 'work' point
-    let sleep_time = config.workloads.get(&work).unwrap().clone();
+    let sleep_time = *config.workloads.get(&work).unwrap();
     // resume the stopwatch to measure the actual work
     stop_watch.resume();
     // perform measured computation
@@ -24,7 +24,7 @@ impl Display for Config {
 }
 
 fn example(_stop_watch: &mut StopWatch, config: Config, work: u64) -> Result<(), anyhow::Error> {
-    let sleep_time = config.workloads.get(&work).unwrap().clone();
+    let sleep_time = *config.workloads.get(&work).unwrap();
     thread::sleep(sleep_time / config.resources);
     Ok(())
 }
@@ -37,7 +37,7 @@ fn modified_example(
     // we introduce a random deviation in modified example to simulate
     // regression introduced by a code change
     let deviation = Duration::from_millis(rand::thread_rng().gen_range(0..10));
-    let sleep_time = config.workloads.get(&work).unwrap().clone();
+    let sleep_time = *config.workloads.get(&work).unwrap();
     thread::sleep(sleep_time / config.resources + deviation);
     Ok(())
 }
@@ -89,7 +89,7 @@ fn main() -> Result<(), anyhow::Error> {
     // compare results of this run with the results of previous runs with threshold of 5 percent
     let analysis_result = current_benchmarks.analyze(Some(previous_summary), 5.0)?;
     println!("Analysis result:");
-    println!("{}", analysis_result.to_string());
+    println!("{}", analysis_result);
     assert!(!analysis_result.divergent_series().is_empty());
     Ok(())
 }
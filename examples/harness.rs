@@ -0,0 +1,44 @@
+use benchmark_rs::benchmark_main;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::stopwatch::StopWatch;
+
+fn sort(
+    _stop_watch: &mut StopWatch,
+    _config: &str,
+    mut input: Vec<u64>,
+) -> Result<(), anyhow::Error> {
+    input.sort();
+    Ok(())
+}
+
+fn sum(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    let _sum: u64 = (0..work).sum();
+    Ok(())
+}
+
+// Run with `cargo run --example harness -- --save-baseline main`, then compare later runs with
+// `cargo run --example harness -- --baseline main`.
+fn suite() -> Result<Benchmarks<&'static str, u64, anyhow::Error>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("harness-example");
+    benchmarks.add(
+        "sum",
+        sum,
+        "no configuration",
+        vec![1000, 10000, 100000],
+        10,
+        1,
+    )?;
+    benchmarks.add_with_setup(
+        "sort",
+        |_config: &&str, work: &u64| Ok((0..*work).rev().collect::<Vec<u64>>()),
+        sort,
+        "reversed input",
+        vec![1000, 10000, 100000],
+        10,
+        1,
+    )?;
+    benchmarks.set_tags("sort", &["slow"])?;
+    Ok(benchmarks)
+}
+
+benchmark_main!(suite);
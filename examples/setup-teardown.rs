@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter};
+use std::thread;
+use std::time::Duration;
+
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::stopwatch::StopWatch;
+
+#[derive(Clone)]
+struct Config {
+    // simulate a lengthy preparation of each workload
+    pub preparation: Duration,
+}
+
+impl Display for Config {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "preparation: {}ms", self.preparation.as_millis())
+    }
+}
+
+fn setup(config: &Config, work: &usize) -> Result<Vec<u64>, anyhow::Error> {
+    // perform potentially lengthy preparation that will not reflect in the measurement
+    thread::sleep(config.preparation);
+    Ok((0..*work as u64).rev().collect())
+}
+
+fn example(
+    _stop_watch: &mut StopWatch,
+    _config: Config,
+    mut input: Vec<u64>,
+) -> Result<(), anyhow::Error> {
+    // only the computation is measured, the input was prepared by the setup
+    input.sort();
+    Ok(())
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("setup-teardown");
+    benchmarks.add_with_setup(
+        "sort",
+        setup,
+        example,
+        Config {
+            preparation: Duration::from_millis(10),
+        },
+        (1..=10).map(|i| i * 100_000).collect(),
+        2,
+        1,
+    )?;
+
+    benchmarks.set_setup(|| {
+        println!("suite setup");
+        Ok(())
+    });
+    benchmarks.set_series_setup("sort", |config: &Config| {
+        println!("series setup, {config}");
+        Ok(())
+    })?;
+    benchmarks.set_point_teardown("sort", |_config: &Config, _work: &usize| {
+        // perform potentially lengthy cleanup that will not reflect in the measurement
+        Ok(())
+    })?;
+    benchmarks.set_series_teardown("sort", |_config: &Config| {
+        println!("series teardown");
+        Ok(())
+    })?;
+    benchmarks.set_teardown(|| {
+        println!("suite teardown");
+        Ok(())
+    });
+
+    benchmarks.run()?;
+
+    println!("Benchmark series in CSV format.");
+    for (k, v) in benchmarks.summary_as_csv(true, true) {
+        println!("Benchmark name: {k}");
+        for line in v {
+            println!("{line}")
+        }
+        println!();
+    }
+
+    Ok(())
+}
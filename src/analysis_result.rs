@@ -1,61 +1,174 @@
 use crate::benchmark_comparison::BenchmarkComparison;
+use crate::failure::Failure;
+use crate::metric::Metric;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
 };
 
+/// Classification of a series by the comparisons of its workload points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Classification {
+    /// All workload points are equal within the threshold
+    Equal,
+    /// Some workload points regressed and none improved
+    Regressed,
+    /// Some workload points improved and none regressed
+    Improved,
+    /// Some workload points regressed and some improved
+    Mixed,
+}
+
+impl Display for Classification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Classification::Equal => "equal",
+            Classification::Regressed => "regressed",
+            Classification::Improved => "improved",
+            Classification::Mixed => "mixed",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// Result of the comparison of two benchmarks
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisResult {
     name: String,
+    #[serde(default)]
+    metric: Metric,
     new_series: HashSet<String>,
+    #[serde(default)]
+    removed_series: HashSet<String>,
+    #[serde(default)]
+    new_points: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    removed_points: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    incomparable_points: HashMap<String, Vec<String>>,
     equal_series: HashMap<String, HashMap<String, BenchmarkComparison>>,
     divergent_series: HashMap<String, HashMap<String, BenchmarkComparison>>,
+    #[serde(default)]
+    regressed_series: HashSet<String>,
+    #[serde(default)]
+    improved_series: HashSet<String>,
+    #[serde(default)]
+    mixed_series: HashSet<String>,
+    #[serde(default)]
+    failed_series: HashMap<String, Vec<Failure>>,
 }
 
 impl AnalysisResult {
-    pub(crate) fn new(name: String) -> AnalysisResult {
+    pub(crate) fn new(name: String, metric: Metric) -> AnalysisResult {
         AnalysisResult {
             name,
+            metric,
             new_series: Default::default(),
+            removed_series: Default::default(),
+            new_points: Default::default(),
+            removed_points: Default::default(),
+            incomparable_points: Default::default(),
             equal_series: Default::default(),
             divergent_series: Default::default(),
+            regressed_series: Default::default(),
+            improved_series: Default::default(),
+            mixed_series: Default::default(),
+            failed_series: Default::default(),
         }
     }
 
+    pub(crate) fn add_failures(&mut self, name: String, failures: Vec<Failure>) {
+        self.failed_series.insert(name, failures);
+    }
+
     pub(crate) fn add_new(&mut self, name: String) {
         self.new_series.insert(name);
     }
 
-    pub(crate) fn add(&mut self, name: String, comparisons: HashMap<String, BenchmarkComparison>) {
-        let equal = comparisons
-            .iter()
-            .fold(
-                true,
-                |equal, (_, benchmark_comparison)| match benchmark_comparison {
-                    BenchmarkComparison::Less { .. } => false,
-                    BenchmarkComparison::Equal { .. } => equal,
-                    BenchmarkComparison::Greater { .. } => false,
-                },
-            );
-        if equal {
-            self.equal_series.insert(name, comparisons);
-        } else {
-            self.divergent_series.insert(name, comparisons);
+    pub(crate) fn add_removed(&mut self, name: String) {
+        self.removed_series.insert(name);
+    }
+
+    pub(crate) fn add_points(&mut self, name: String, new: Vec<String>, removed: Vec<String>) {
+        if !new.is_empty() {
+            self.new_points.insert(name.clone(), new);
+        }
+        if !removed.is_empty() {
+            self.removed_points.insert(name, removed);
+        }
+    }
+
+    pub(crate) fn add_incomparable(&mut self, name: String, points: Vec<String>) {
+        if !points.is_empty() {
+            self.incomparable_points.insert(name, points);
         }
     }
 
+    pub(crate) fn add(&mut self, name: String, comparisons: HashMap<String, BenchmarkComparison>) {
+        let regressed = comparisons.values().any(|c| c.is_regression());
+        let improved = comparisons.values().any(|c| c.is_improvement());
+        match (regressed, improved) {
+            (false, false) => {
+                self.equal_series.insert(name, comparisons);
+                return;
+            }
+            (true, false) => self.regressed_series.insert(name.clone()),
+            (false, true) => self.improved_series.insert(name.clone()),
+            (true, true) => self.mixed_series.insert(name.clone()),
+        };
+        self.divergent_series.insert(name, comparisons);
+    }
+
     /// Name of the [crate::benchmarks::Benchmarks] suite that was analyzed
     pub fn name(&self) -> &String {
         &self.name
     }
 
+    /// Metric the workload points were compared by
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
     /// Names of series that are new in the current run
     pub fn new_series(&self) -> &HashSet<String> {
         &self.new_series
     }
 
+    /// Names of series that were run in the previous run and not in the current run
+    ///
+    /// Series skipped by the filter of the current run are not considered removed, see
+    /// [crate::summary::Summary::skipped].
+    pub fn removed_series(&self) -> &HashSet<String> {
+        &self.removed_series
+    }
+
+    /// Workload points that are new in the current run, by series name
+    pub fn new_points(&self) -> &HashMap<String, Vec<String>> {
+        &self.new_points
+    }
+
+    /// Workload points of the previous run that are missing in the current run, by series name
+    pub fn removed_points(&self) -> &HashMap<String, Vec<String>> {
+        &self.removed_points
+    }
+
+    /// Workload points of both runs that cannot be compared by the metric, by series name, see
+    /// [crate::comparison::compare_runs]
+    pub fn incomparable_points(&self) -> &HashMap<String, Vec<String>> {
+        &self.incomparable_points
+    }
+
+    /// Check if any series or workload point of the previous run is missing in the current run
+    pub fn has_removed(&self) -> bool {
+        !self.removed_series.is_empty() || !self.removed_points.is_empty()
+    }
+
+    /// Check if any workload point of both runs cannot be compared by the metric
+    pub fn has_incomparable(&self) -> bool {
+        !self.incomparable_points.is_empty()
+    }
+
     /// Series that are equal within provided threshold
     pub fn equal_series(&self) -> &HashMap<String, HashMap<String, BenchmarkComparison>> {
         &self.equal_series
@@ -66,10 +179,107 @@ impl AnalysisResult {
         &self.divergent_series
     }
 
+    /// Failures of the current run by series name
+    pub fn failed_series(&self) -> &HashMap<String, Vec<Failure>> {
+        &self.failed_series
+    }
+
+    /// Check if any series of the current run recorded a failure
+    pub fn has_failures(&self) -> bool {
+        self.failed_series
+            .values()
+            .any(|failures| !failures.is_empty())
+    }
+
+    /// Names of divergent series with regressed and without improved workload points
+    pub fn regressed_series(&self) -> &HashSet<String> {
+        &self.regressed_series
+    }
+
+    /// Names of divergent series with improved and without regressed workload points
+    pub fn improved_series(&self) -> &HashSet<String> {
+        &self.improved_series
+    }
+
+    /// Names of divergent series with both regressed and improved workload points
+    pub fn mixed_series(&self) -> &HashSet<String> {
+        &self.mixed_series
+    }
+
+    /// Get the [Classification] of the series `name`, `None` for series that were not compared
+    pub fn classification(&self, name: &str) -> Option<Classification> {
+        if self.equal_series.contains_key(name) {
+            Some(Classification::Equal)
+        } else if self.regressed_series.contains(name) {
+            Some(Classification::Regressed)
+        } else if self.improved_series.contains(name) {
+            Some(Classification::Improved)
+        } else if self.mixed_series.contains(name) {
+            Some(Classification::Mixed)
+        } else {
+            None
+        }
+    }
+
+    /// Number of regressed workload points in all series
+    pub fn regression_count(&self) -> usize {
+        self.comparisons()
+            .filter(|(_, c)| c.is_regression())
+            .count()
+    }
+
+    /// Number of improved workload points in all series
+    pub fn improvement_count(&self) -> usize {
+        self.comparisons()
+            .filter(|(_, c)| c.is_improvement())
+            .count()
+    }
+
+    /// Up to `limit` regressed workload points with their series names, the worst first
+    pub fn worst_regressions(&self, limit: usize) -> Vec<(&String, &BenchmarkComparison)> {
+        let mut regressions: Vec<_> = self
+            .comparisons()
+            .filter(|(_, c)| c.is_regression())
+            .collect();
+        regressions.sort_by(|(_, a), (_, b)| b.regression().total_cmp(&a.regression()));
+        regressions.truncate(limit);
+        regressions
+    }
+
+    /// Up to `limit` improved workload points with their series names, the best first
+    pub fn best_improvements(&self, limit: usize) -> Vec<(&String, &BenchmarkComparison)> {
+        let mut improvements: Vec<_> = self
+            .comparisons()
+            .filter(|(_, c)| c.is_improvement())
+            .collect();
+        improvements.sort_by(|(_, a), (_, b)| a.regression().total_cmp(&b.regression()));
+        improvements.truncate(limit);
+        improvements
+    }
+
+    /// Check if any workload point improved beyond the threshold, see
+    /// [BenchmarkComparison::is_improvement]
+    pub fn has_improvements(&self) -> bool {
+        self.improvement_count() > 0
+    }
+
+    /// Check if any workload point got worse than in the previous run beyond the threshold, see
+    /// [BenchmarkComparison::is_regression]
+    pub fn has_regressions(&self) -> bool {
+        self.regression_count() > 0
+    }
+
     /// Series that are divergent within provided threshold
     pub fn results(&self) -> &HashMap<String, HashMap<String, BenchmarkComparison>> {
         self.divergent_series()
     }
+
+    /// Comparisons of the divergent series with their series names
+    fn comparisons(&self) -> impl Iterator<Item = (&String, &BenchmarkComparison)> {
+        self.divergent_series
+            .iter()
+            .flat_map(|(name, comparisons)| comparisons.values().map(move |c| (name, c)))
+    }
 }
 
 impl Display for AnalysisResult {
@@ -1,45 +1,271 @@
-use crate::run_summary::RunSummary;
+use crate::bootstrap::Bootstrap;
+use crate::failure::{Failure, FailureKind};
+use crate::isolation::IsolatedSeries;
+use crate::outliers::OutlierPolicy;
+use crate::repetition::Repetition;
+use crate::run_summary::{RunSummary, Sample, SummaryOptions};
 use crate::series_summary::SeriesSummary;
+use crate::statistics;
 use crate::stopwatch::StopWatch;
+use crate::threading::{Dispatch, Threading, Worker};
 use anyhow::Error;
-use statrs::statistics::{Distribution, Max, Median, Min};
-use std::fmt::Display;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
 
-pub(crate) struct Benchmark<C, W, E>
+/// Upper limit for the number of invocations in a batch
+const MAX_BATCH: usize = 1 << 20;
+
+/// Upper limit for the number of argument sets of a batch that are held in memory at once
+const MAX_PREPARED: usize = 1 << 10;
+
+/// Percentiles computed for each workload point unless configured otherwise
+pub(crate) const DEFAULT_PERCENTILES: [f64; 6] = [5.0, 25.0, 75.0, 90.0, 95.0, 99.0];
+
+/// Failure that is recorded in the [SeriesSummary] regardless of the `continue_on_error` setting
+#[derive(Debug)]
+struct Interruption {
+    kind: FailureKind,
+    message: String,
+}
+
+impl Interruption {
+    fn panic(payload: Box<dyn Any + Send>) -> Interruption {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "panic with a non string payload".to_string(),
+            },
+        };
+        Interruption {
+            kind: FailureKind::Panic,
+            message,
+        }
+    }
+
+    fn timeout(message: String) -> Interruption {
+        Interruption {
+            kind: FailureKind::Timeout,
+            message,
+        }
+    }
+}
+
+impl Display for Interruption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for Interruption {}
+
+/// Invoke `f` and convert a panic to an [Interruption] error
+pub(crate) fn isolate<T, F>(f: F) -> Result<T, Error>
+where
+    F: FnOnce() -> Result<T, Error>,
+{
+    catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Interruption::panic(payload).into()))
+}
+
+/// Invoke `f` `batch` times with arguments made by `prepare`
+///
+/// The arguments are prepared in chunks of at most [MAX_PREPARED] while the stopwatch is stopped,
+/// so that a large batch does not hold all of its arguments in memory.
+fn invoke_batch<A, E>(
+    stop_watch: &mut StopWatch,
+    batch: usize,
+    mut prepare: impl FnMut() -> Result<A, E>,
+    mut f: impl FnMut(&mut StopWatch, A) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut arguments = Vec::with_capacity(batch.min(MAX_PREPARED));
+    let mut remaining = batch;
+    while remaining > 0 {
+        let chunk = remaining.min(MAX_PREPARED);
+        for _i in 0..chunk {
+            arguments.push(prepare()?);
+        }
+        remaining -= chunk;
+        stop_watch.start();
+        let result = arguments
+            .drain(..)
+            .try_for_each(|arguments| f(stop_watch, arguments));
+        stop_watch.stop();
+        result?;
+    }
+    Ok(())
+}
+
+/// Invoke the benchmark function `batch` times with clones of the configuration and the workload
+/// point, see [invoke_batch]
+pub(crate) fn call_by_value<C, W, E, F>(
+    f: &mut F,
+    stop_watch: &mut StopWatch,
+    config: &C,
+    workload_point: &W,
+    batch: usize,
+) -> Result<(), E>
+where
+    C: Clone,
+    W: Clone,
+    F: FnMut(&mut StopWatch, C, W) -> Result<(), E> + ?Sized,
+{
+    invoke_batch(
+        stop_watch,
+        batch,
+        || Ok((config.clone(), workload_point.clone())),
+        |stop_watch, (config, workload_point)| f(stop_watch, config, workload_point),
+    )
+}
+
+/// Invoke the benchmark function `batch` times with the configuration and the workload point
+/// borrowed
+pub(crate) fn call_by_reference<C, W, E, F>(
+    f: &mut F,
+    stop_watch: &mut StopWatch,
+    config: &C,
+    workload_point: &W,
+    batch: usize,
+) -> Result<(), E>
+where
+    F: FnMut(&mut StopWatch, &C, &W) -> Result<(), E> + ?Sized,
+{
+    stop_watch.start();
+    let result = (0..batch).try_for_each(|_i| f(stop_watch, config, workload_point));
+    stop_watch.stop();
+    result
+}
+
+/// Invoke the benchmark function `batch` times with inputs produced by `setup`, see
+/// [invoke_batch]
+pub(crate) fn call_staged<C, W, E, I, S, F>(
+    setup: &mut S,
+    f: &mut F,
+    stop_watch: &mut StopWatch,
+    config: &C,
+    workload_point: &W,
+    batch: usize,
+) -> Result<(), E>
+where
+    C: Clone,
+    S: FnMut(&C, &W) -> Result<I, E> + ?Sized,
+    F: FnMut(&mut StopWatch, C, I) -> Result<(), E> + ?Sized,
+{
+    invoke_batch(
+        stop_watch,
+        batch,
+        || Ok((config.clone(), setup(config, workload_point)?)),
+        |stop_watch, (config, input)| f(stop_watch, config, input),
+    )
+}
+
+/// Invoke the benchmark function `batch` times with inputs produced by `setup` and released by
+/// `teardown` right after each invocation, while the stopwatch is stopped. `called` is invoked
+/// between the invocation and the release of its input.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn call_disposable<C, W, E, I, S, F, D>(
+    setup: &mut S,
+    f: &mut F,
+    teardown: &mut D,
+    stop_watch: &mut StopWatch,
+    config: &C,
+    workload_point: &W,
+    batch: usize,
+    called: &mut dyn FnMut(),
+) -> Result<(), E>
+where
+    C: Clone,
+    S: FnMut(&C, &W) -> Result<I, E> + ?Sized,
+    F: FnMut(&mut StopWatch, C, &mut I) -> Result<(), E> + ?Sized,
+    D: FnMut(&C, &W, I) -> Result<(), E> + ?Sized,
+{
+    for _i in 0..batch {
+        let mut input = setup(config, workload_point)?;
+        let argument = config.clone();
+        stop_watch.start();
+        let result = f(stop_watch, argument, &mut input);
+        stop_watch.stop();
+        called();
+        let released = teardown(config, workload_point, input);
+        result?;
+        released?;
+    }
+    Ok(())
+}
+
+pub(crate) enum BenchmarkFn<C, W, E, T: Threading> {
+    Function(fn(&mut StopWatch, C, W) -> Result<(), E>),
+    /// Closure, benchmark function that takes its arguments by reference or setup and benchmark
+    /// function, see [crate::threading::Movable]. `None` while it is moved to a [Worker].
+    Boxed(Option<Box<T::Invoke<C, W, E>>>),
+}
+
+pub(crate) struct Benchmark<C, W, E, T>
 where
     C: Clone + Display,
     W: Clone + Display,
     Error: From<E>,
+    T: Threading,
 {
     name: String,
     config: C,
     work: Vec<W>,
-    f: fn(&mut StopWatch, C, W) -> Result<(), E>,
+    f: BenchmarkFn<C, W, E, T>,
+    worker: Option<Worker<E, T::Invoke<C, W, E>>>,
     repeat: usize,
     ramp_up: usize,
+    repetition: Repetition,
+    batching: Option<Duration>,
+    iteration: usize,
+    iteration_timeout: Option<Duration>,
+    point_timeout: Option<Duration>,
+    point_started: Instant,
+    series_setup: Option<Box<T::SeriesHook<C, E>>>,
+    series_teardown: Option<Box<T::SeriesHook<C, E>>>,
+    point_teardown: Option<Box<T::PointHook<C, W, E>>>,
+    releases_input: bool,
+    tags: Vec<String>,
+    summary_options: SummaryOptions,
 }
 
-impl<C, W, E> Benchmark<C, W, E>
+impl<C, W, E, T> Benchmark<C, W, E, T>
 where
     C: Clone + Display,
     W: Clone + Display,
     Error: From<E>,
+    T: Threading,
 {
     pub(crate) fn new(
         name: String,
-        f: fn(&mut StopWatch, C, W) -> Result<(), E>,
+        f: BenchmarkFn<C, W, E, T>,
         config: C,
         work: Vec<W>,
         repeat: usize,
         ramp_up: usize,
-    ) -> Benchmark<C, W, E> {
+    ) -> Benchmark<C, W, E, T> {
         Benchmark {
             name,
             config,
             work,
             f,
+            worker: None,
             repeat,
             ramp_up,
+            repetition: Repetition::Fixed,
+            batching: None,
+            iteration: 0,
+            iteration_timeout: None,
+            point_timeout: None,
+            point_started: Instant::now(),
+            series_setup: None,
+            series_teardown: None,
+            point_teardown: None,
+            releases_input: false,
+            tags: vec![],
+            summary_options: SummaryOptions::default(),
         }
     }
 
@@ -47,55 +273,420 @@ where
         &self.name
     }
 
-    pub(crate) fn run(&self) -> Result<SeriesSummary, Error> {
-        let mut series_summary = SeriesSummary::new(self.name.clone(), self.config.to_string());
-        let mut error: Option<Error> = None;
-        for w in &self.work {
-            for _i in 0..self.ramp_up {
-                let mut stop_watch = StopWatch::new();
-                (self.f)(&mut stop_watch, self.config.clone(), w.clone())?
+    pub(crate) fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub(crate) fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags;
+    }
+
+    pub(crate) fn set_percentiles(&mut self, percentiles: Vec<f64>) {
+        self.summary_options.percentiles = percentiles;
+    }
+
+    pub(crate) fn set_retain_samples(&mut self, retain_samples: bool) {
+        self.summary_options.retain_samples = retain_samples;
+    }
+
+    pub(crate) fn set_bootstrap(&mut self, bootstrap: Option<Bootstrap>) {
+        self.summary_options.bootstrap = bootstrap;
+    }
+
+    pub(crate) fn set_outlier_policy(&mut self, outlier_policy: OutlierPolicy) {
+        self.summary_options.outlier_policy = outlier_policy;
+    }
+
+    pub(crate) fn set_repetition(&mut self, repetition: Repetition) {
+        self.repetition = repetition;
+    }
+
+    pub(crate) fn set_batching(&mut self, min_sample_duration: Duration) {
+        self.batching = Some(min_sample_duration);
+    }
+
+    pub(crate) fn set_iteration_timeout(&mut self, timeout: Duration) {
+        self.iteration_timeout = Some(timeout);
+    }
+
+    pub(crate) fn set_point_timeout(&mut self, timeout: Duration) {
+        self.point_timeout = Some(timeout);
+    }
+
+    pub(crate) fn set_series_setup(&mut self, f: Box<T::SeriesHook<C, E>>) {
+        self.series_setup = Some(f);
+    }
+
+    pub(crate) fn set_series_teardown(&mut self, f: Box<T::SeriesHook<C, E>>) {
+        self.series_teardown = Some(f);
+    }
+
+    pub(crate) fn set_point_teardown(&mut self, f: Box<T::PointHook<C, W, E>>) {
+        self.point_teardown = Some(f);
+    }
+
+    /// Dispatch the benchmark function once per invocation, so that the release of the input of
+    /// [crate::benchmarks::Benchmarks::add_with_setup_and_teardown] is excluded from the timeouts
+    pub(crate) fn set_releases_input(&mut self) {
+        self.releases_input = true;
+    }
+
+    /// Describe this benchmark series for a run in child processes
+    pub(crate) fn isolated_series(&self) -> IsolatedSeries {
+        IsolatedSeries {
+            name: self.name.clone(),
+            series_summary: self.series_summary(),
+            points: self.work.iter().map(|w| w.to_string()).collect(),
+            point_timeout: self.point_timeout,
+        }
+    }
+
+    /// Create an empty [SeriesSummary] for this benchmark series
+    pub(crate) fn series_summary(&self) -> SeriesSummary {
+        SeriesSummary::new(self.name.clone(), self.config.to_string())
+    }
+}
+
+impl<C, W, E, T> Benchmark<C, W, E, T>
+where
+    C: Clone + Display,
+    W: Clone + Display,
+    Error: From<E>,
+    T: Dispatch<C, W, E>,
+{
+    /// Run the benchmark series
+    ///
+    /// * `continue_on_error` - record a failed workload point in the [SeriesSummary] and carry
+    ///   on with the next one, instead of returning the error
+    pub(crate) fn run(&mut self, continue_on_error: bool) -> Result<SeriesSummary, Error> {
+        let mut series_summary = self.series_summary();
+        self.run_reporting(0..self.work.len(), continue_on_error, &mut |fragment| {
+            series_summary.merge(fragment)
+        })?;
+        Ok(series_summary)
+    }
+
+    /// Run the workload points in `points`
+    ///
+    /// The outcome of each workload point, and the failure of the series setup or teardown, is
+    /// passed to `report` as a [SeriesSummary] fragment as soon as it is known.
+    pub(crate) fn run_reporting(
+        &mut self,
+        points: Range<usize>,
+        continue_on_error: bool,
+        report: &mut dyn FnMut(SeriesSummary),
+    ) -> Result<(), Error> {
+        if let Err(e) = self.run_series(points, continue_on_error, report) {
+            let mut fragment = self.series_summary();
+            fragment.add_failure(Self::failure(None, 0, e, continue_on_error)?);
+            report(fragment);
+        }
+        Ok(())
+    }
+
+    /// Convert an error to a [Failure], or return the error if it should stop the run
+    fn failure(
+        point: Option<String>,
+        iteration: usize,
+        e: Error,
+        continue_on_error: bool,
+    ) -> Result<Failure, Error> {
+        match e.downcast::<Interruption>() {
+            Ok(interruption) => Ok(Failure::new(
+                point,
+                iteration,
+                interruption.kind,
+                interruption.message,
+            )),
+            Err(e) if continue_on_error => Ok(Failure::new(
+                point,
+                iteration,
+                FailureKind::Error,
+                format!("{e:#}"),
+            )),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn run_series(
+        &mut self,
+        points: Range<usize>,
+        continue_on_error: bool,
+        report: &mut dyn FnMut(SeriesSummary),
+    ) -> Result<(), Error> {
+        if let Some(setup) = &mut self.series_setup {
+            isolate(|| Ok(setup(&self.config)?))?;
+        }
+        let result = self.run_points(points, continue_on_error, report);
+        if let Some(teardown) = &mut self.series_teardown {
+            isolate(|| Ok(teardown(&self.config)?))?;
+        }
+        result
+    }
+
+    fn run_points(
+        &mut self,
+        points: Range<usize>,
+        continue_on_error: bool,
+        report: &mut dyn FnMut(SeriesSummary),
+    ) -> Result<(), Error> {
+        for i in points.clone() {
+            if !self.reclaim() {
+                return Err(Interruption::timeout(format!(
+                    "benchmark function was abandoned after a timeout, {} workload points were not run",
+                    points.end - i
+                ))
+                .into());
             }
-            let mut durations = Vec::new();
-            for _i in 0..self.repeat {
-                let mut stop_watch = StopWatch::new();
-                stop_watch.start();
-                match (self.f)(&mut stop_watch, self.config.clone(), w.clone()) {
-                    Ok(_) => {
-                        stop_watch.stop();
-                        durations.push(stop_watch.accumulated())
-                    }
-                    Err(e) => {
-                        error = Some(e.into());
-                        break;
-                    }
+            let w = self.work[i].clone();
+            self.iteration = 0;
+            self.watch(&w);
+            self.point_started = Instant::now();
+            let mut fragment = self.series_summary();
+            match self.run_point(&w) {
+                Ok(run_summary) => fragment.add(w.to_string(), run_summary),
+                Err(e) => fragment.add_failure(Self::failure(
+                    Some(w.to_string()),
+                    self.iteration,
+                    e,
+                    continue_on_error,
+                )?),
+            }
+            // an abandoned invocation is waited for before the next workload point
+            if self
+                .worker
+                .as_ref()
+                .is_some_and(|worker| !worker.abandoned())
+            {
+                self.reclaim();
+            }
+            report(fragment);
+        }
+        Ok(())
+    }
+
+    /// Move the benchmark function to a worker thread for the workload point `w` when a timeout
+    /// is set, see [Dispatch::watch]
+    fn watch(&mut self, w: &W) {
+        if self.iteration_timeout.is_none() && self.point_timeout.is_none() {
+            return;
+        }
+        self.worker = match &mut self.f {
+            BenchmarkFn::Function(f) => T::watch_function(*f, &self.config, w),
+            BenchmarkFn::Boxed(f) => match f.take().map(|f| T::watch(f, &self.config, w)) {
+                Some(Ok(worker)) => Some(worker),
+                Some(Err(local)) => {
+                    *f = Some(local);
+                    None
                 }
+                None => None,
+            },
+        };
+    }
+
+    /// Take the benchmark function back from the worker thread of the last workload point
+    ///
+    /// An invocation that was abandoned after a timeout may still be running. A closure is not
+    /// invoked again, nor is the point teardown performed, until it returns, and it is waited for
+    /// at most as long as the timeouts of a workload point allow. A function added with
+    /// [crate::benchmarks::Benchmarks::add] has no state and is not waited for.
+    ///
+    /// Returns false when the closure did not return in time.
+    fn reclaim(&mut self) -> bool {
+        let Some(worker) = &mut self.worker else {
+            return true;
+        };
+        let timeout = match (self.iteration_timeout, self.point_timeout) {
+            (Some(iteration), Some(point)) => Some(iteration.min(point)),
+            (iteration, point) => iteration.or(point),
+        };
+        match &mut self.f {
+            BenchmarkFn::Function(_) => {}
+            BenchmarkFn::Boxed(f) => match worker.reclaim(timeout.filter(|_| worker.abandoned())) {
+                Some(returned) => *f = Some(returned),
+                None => return false,
+            },
+        }
+        self.worker = None;
+        true
+    }
+
+    /// Invoke the benchmark function `batch` times, on the worker thread when there is one
+    ///
+    /// Returns the result and the time spent releasing the input, see [Threading::Invoke], or
+    /// `None` when the invocation did not return within `timeout`.
+    fn invoke(
+        &mut self,
+        stop_watch: &mut StopWatch,
+        w: &W,
+        batch: usize,
+        timeout: Option<Duration>,
+    ) -> Option<(Result<(), E>, Duration)> {
+        if let Some(worker) = &mut self.worker {
+            return worker.call(stop_watch, batch, timeout);
+        }
+        let mut called = None;
+        let result = match &mut self.f {
+            BenchmarkFn::Function(f) => call_by_value(f, stop_watch, &self.config, w, batch),
+            BenchmarkFn::Boxed(f) => f.as_mut()?(stop_watch, &self.config, w, batch, &mut || {
+                called = Some(Instant::now())
+            }),
+        };
+        Some((
+            result,
+            called.map_or(Duration::ZERO, |called: Instant| called.elapsed()),
+        ))
+    }
+
+    fn run_point(&mut self, w: &W) -> Result<RunSummary, Error> {
+        for _i in 0..self.ramp_up {
+            self.sample(w, 1)?;
+        }
+        let batch = match self.batching {
+            None => 1,
+            Some(min_sample_duration) => self.calibrate(w, min_sample_duration)?,
+        };
+        let samples = self.measure(w, batch)?;
+        Ok(RunSummary::new(
+            self.name.clone(),
+            self.ramp_up,
+            batch,
+            samples,
+            &self.summary_options,
+        ))
+    }
+
+    /// Find the number of invocations in a batch so that a single sample lasts at least
+    /// `min_sample_duration`
+    fn calibrate(&mut self, w: &W, min_sample_duration: Duration) -> Result<usize, Error> {
+        let min_sample_nanos = min_sample_duration.as_nanos() as f64;
+        let mut batch = 1;
+        loop {
+            let elapsed = self.sample(w, batch)? * batch as f64;
+            if elapsed >= min_sample_nanos || batch >= MAX_BATCH {
+                break Ok(batch);
             }
-            match error {
-                None => {
-                    let data: statrs::statistics::Data<Vec<f64>> = statrs::statistics::Data::new(
-                        durations.into_iter().map(|d| d.as_nanos() as f64).collect(),
-                    );
-
-                    let run_summary = RunSummary::new(
-                        self.name.clone(),
-                        self.ramp_up,
-                        self.repeat,
-                        data.min() as u64,
-                        data.max() as u64,
-                        data.median() as u64,
-                        data.std_dev(),
-                    );
-                    series_summary.add(w.to_string(), run_summary);
+            let factor = if elapsed > 0.0 {
+                (min_sample_nanos / elapsed).ceil().clamp(2.0, 10.0) as usize
+            } else {
+                10
+            };
+            batch = (batch * factor).min(MAX_BATCH);
+        }
+    }
+
+    /// Repeat the benchmark for workload point `w` as defined by the [Repetition] policy and
+    /// return the measured samples
+    fn measure(&mut self, w: &W, batch: usize) -> Result<Vec<Sample>, Error> {
+        let mut samples = Vec::new();
+        let mut durations = Vec::new();
+        match self.repetition.clone() {
+            Repetition::Fixed => {
+                for _i in 0..self.repeat {
+                    samples.push(Sample::new(self.iteration, self.sample(w, batch)?));
                 }
-                Some(_) => {
-                    break;
+            }
+            Repetition::Adaptive {
+                precision,
+                time_budget,
+                min_repeat,
+                max_repeat,
+            } => {
+                let started = Instant::now();
+                while durations.len() < max_repeat {
+                    samples.push(Sample::new(self.iteration, self.sample(w, batch)?));
+                    durations.push(samples[samples.len() - 1].nanos());
+                    if durations.len() >= min_repeat {
+                        let precise = statistics::median_precision(&durations)
+                            .map(|p| p <= precision)
+                            .unwrap_or(false);
+                        if precise || started.elapsed() >= time_budget {
+                            break;
+                        }
+                    }
                 }
             }
         }
+        Ok(samples)
+    }
 
-        match error {
-            None => Ok(series_summary),
-            Some(e) => Err(e),
+    /// Invoke the benchmark function `batch` times for workload point `w` and return the
+    /// duration of a single invocation in nanoseconds. Point setup and teardown are performed
+    /// while the stopwatch is stopped. A panic or an exceeded timeout is returned as an
+    /// [Interruption] error.
+    ///
+    /// With a point teardown or an input to release, the benchmark function is dispatched once
+    /// per invocation and the teardown follows each invocation. The time spent in teardowns is
+    /// excluded from both timeouts.
+    fn sample(&mut self, w: &W, batch: usize) -> Result<f64, Error> {
+        let mut stop_watch = StopWatch::new();
+        let (calls, per_call) = if self.point_teardown.is_some() || self.releases_input {
+            (batch, 1)
+        } else {
+            (1, batch)
+        };
+        let mut elapsed = Duration::ZERO;
+        for _i in 0..calls {
+            let started = Instant::now();
+            let timeout = self.remaining_time(batch, elapsed);
+            let result = isolate(|| {
+                Ok(self
+                    .invoke(&mut stop_watch, w, per_call, timeout)
+                    .map(|(result, released)| (result.map_err(Error::from), released)))
+            });
+            stop_watch.stop();
+            let Some((result, released)) = result? else {
+                self.check_timeouts((elapsed + started.elapsed()) / batch as u32)?;
+                return Err(Interruption::timeout(
+                    "benchmark function was abandoned after a timeout".to_string(),
+                )
+                .into());
+            };
+            let called = started.elapsed() - released;
+            let teardown_started = Instant::now();
+            if let Some(teardown) = self.point_teardown.as_mut() {
+                isolate(|| Ok(teardown(&self.config, w)?))?;
+            }
+            elapsed += called;
+            self.point_started += released + teardown_started.elapsed();
+            result?;
+        }
+        self.check_timeouts(elapsed / batch as u32)?;
+        self.iteration += 1;
+        Ok(stop_watch.accumulated().as_nanos() as f64 / batch as f64)
+    }
+
+    /// The time the benchmark function may take to complete `batch` invocations, of which
+    /// `elapsed` has passed, before the iteration or the point timeout is exceeded
+    fn remaining_time(&self, batch: usize, elapsed: Duration) -> Option<Duration> {
+        let iteration_remaining = self
+            .iteration_timeout
+            .map(|timeout| timeout.saturating_mul(batch as u32).saturating_sub(elapsed));
+        let point_remaining = self
+            .point_timeout
+            .map(|timeout| timeout.saturating_sub(self.point_started.elapsed()));
+        match (iteration_remaining, point_remaining) {
+            (Some(iteration), Some(point)) => Some(iteration.min(point)),
+            (iteration, point) => iteration.or(point),
+        }
+    }
+
+    /// Check the wall clock time of the last invocation and of the current workload point
+    /// against the timeouts
+    fn check_timeouts(&self, invocation_elapsed: Duration) -> Result<(), Error> {
+        match (self.iteration_timeout, self.point_timeout) {
+            (Some(timeout), _) if invocation_elapsed > timeout => Err(Interruption::timeout(
+                format!("iteration timeout of {timeout:?} exceeded: {invocation_elapsed:?}"),
+            )
+            .into()),
+            (_, Some(timeout)) if self.point_started.elapsed() > timeout => {
+                Err(Interruption::timeout(format!(
+                    "point timeout of {timeout:?} exceeded: {:?}",
+                    self.point_started.elapsed()
+                ))
+                .into())
+            }
+            _ => Ok(()),
         }
     }
 }
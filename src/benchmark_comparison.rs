@@ -1,24 +1,124 @@
 use serde::{Deserialize, Serialize};
 
+use crate::metric::Metric;
+
 /// Describe equality or inequality relationship between two runs
+///
+/// `previous` and `current` are the values of `metric`, see [Metric::unit]. `p_value` and
+/// `effect_size` are set when the runs were compared with a
+/// [crate::comparison::SignificanceTest].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BenchmarkComparison {
     Less {
         point: String,
-        previous: u64,
-        current: u64,
+        previous: f64,
+        current: f64,
         change: f64,
+        #[serde(default)]
+        metric: Metric,
+        #[serde(default)]
+        p_value: Option<f64>,
+        #[serde(default)]
+        effect_size: Option<f64>,
     },
     Equal {
         point: String,
-        previous: u64,
-        current: u64,
+        previous: f64,
+        current: f64,
         change: f64,
+        #[serde(default)]
+        metric: Metric,
+        #[serde(default)]
+        p_value: Option<f64>,
+        #[serde(default)]
+        effect_size: Option<f64>,
     },
     Greater {
         point: String,
-        previous: u64,
-        current: u64,
+        previous: f64,
+        current: f64,
         change: f64,
+        #[serde(default)]
+        metric: Metric,
+        #[serde(default)]
+        p_value: Option<f64>,
+        #[serde(default)]
+        effect_size: Option<f64>,
     },
 }
+
+impl BenchmarkComparison {
+    /// Workload point the runs were compared at
+    pub fn point(&self) -> &String {
+        match self {
+            BenchmarkComparison::Less { point, .. }
+            | BenchmarkComparison::Equal { point, .. }
+            | BenchmarkComparison::Greater { point, .. } => point,
+        }
+    }
+
+    /// Change of the metric in percents
+    pub fn change(&self) -> f64 {
+        match self {
+            BenchmarkComparison::Less { change, .. }
+            | BenchmarkComparison::Equal { change, .. }
+            | BenchmarkComparison::Greater { change, .. } => *change,
+        }
+    }
+
+    /// Change in percents, positive when the current run is worse regardless of the direction
+    /// of the metric, see [Metric::higher_is_better]
+    pub fn regression(&self) -> f64 {
+        if self.metric().higher_is_better() {
+            -self.change()
+        } else {
+            self.change()
+        }
+    }
+
+    /// Metric the runs were compared by
+    pub fn metric(&self) -> Metric {
+        match self {
+            BenchmarkComparison::Less { metric, .. }
+            | BenchmarkComparison::Equal { metric, .. }
+            | BenchmarkComparison::Greater { metric, .. } => *metric,
+        }
+    }
+
+    /// Check if the current run is worse than the previous run beyond the threshold, slower for
+    /// duration metrics and lower for [Metric::Throughput]
+    pub fn is_regression(&self) -> bool {
+        match self {
+            BenchmarkComparison::Less { metric, .. } => metric.higher_is_better(),
+            BenchmarkComparison::Equal { .. } => false,
+            BenchmarkComparison::Greater { metric, .. } => !metric.higher_is_better(),
+        }
+    }
+
+    /// Check if the current run is better than the previous run beyond the threshold, faster
+    /// for duration metrics and higher for [Metric::Throughput]
+    pub fn is_improvement(&self) -> bool {
+        !matches!(self, BenchmarkComparison::Equal { .. }) && !self.is_regression()
+    }
+
+    /// p-value of the significance test, see [crate::comparison::SignificanceTest]
+    pub fn p_value(&self) -> Option<f64> {
+        match self {
+            BenchmarkComparison::Less { p_value, .. }
+            | BenchmarkComparison::Equal { p_value, .. }
+            | BenchmarkComparison::Greater { p_value, .. } => *p_value,
+        }
+    }
+
+    /// Effect size of the significance test, Cohen's d for
+    /// [crate::comparison::SignificanceTest::Welch] and the rank-biserial correlation for
+    /// [crate::comparison::SignificanceTest::MannWhitney]. Positive when the samples of the
+    /// current run are slower.
+    pub fn effect_size(&self) -> Option<f64> {
+        match self {
+            BenchmarkComparison::Less { effect_size, .. }
+            | BenchmarkComparison::Equal { effect_size, .. }
+            | BenchmarkComparison::Greater { effect_size, .. } => *effect_size,
+        }
+    }
+}
@@ -2,37 +2,65 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::fs::{create_dir_all, File};
 use std::io::{BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
 
 use anyhow::{anyhow, Context, Error};
 
 use crate::analysis_result::AnalysisResult;
-use crate::benchmark::Benchmark;
-use crate::benchmark_comparison::BenchmarkComparison;
-use crate::run_summary::RunSummary;
+use crate::benchmark::{isolate, Benchmark, BenchmarkFn, DEFAULT_PERCENTILES};
+use crate::bootstrap::Bootstrap;
+use crate::comparison::{self, ComparisonOptions, SignificanceTest};
+use crate::filter::Filter;
+use crate::isolation::{self, Isolation, Selection};
+use crate::metric::Metric;
+use crate::outliers::OutlierPolicy;
+use crate::repetition::Repetition;
 use crate::series_summary::SeriesSummary;
 use crate::stopwatch::StopWatch;
 use crate::summary::Summary;
+use crate::threading::{Dispatch, Local, Movable, Threaded, Threading};
+use crate::threshold::ThresholdPolicy;
+
+type SuiteHook<E> = Box<dyn FnMut() -> Result<(), E>>;
 
 /// Run and analyze a benchmarks suite
 ///
 /// * `C` - configuration
 /// * `W` - workload unit
 /// * `E` - error type
+/// * `T` - thread safety of the benchmark functions and hooks, see [Threading]
 ///
-pub struct Benchmarks<C, W, E>
+pub struct Benchmarks<C, W, E, T = Local>
 where
     C: Clone + Display,
     W: Clone + Display,
     Error: From<E>,
+    T: Threading,
 {
     name: String,
     names: HashSet<String>,
-    benchmarks: Vec<Benchmark<C, W, E>>,
+    benchmarks: Vec<Benchmark<C, W, E, T>>,
     summaries: HashMap<String, SeriesSummary>,
+    setup: Option<SuiteHook<E>>,
+    teardown: Option<SuiteHook<E>>,
+    continue_on_error: bool,
+    isolation: Isolation,
+    workers: usize,
+    filter: Filter,
+    skipped: Vec<String>,
+    percentiles: Vec<f64>,
+    csv_statistics: bool,
+    retain_samples: bool,
+    bootstrap: Option<Bootstrap>,
+    repetition: Repetition,
+    comparison_options: ComparisonOptions,
 }
 
-impl<C, W, E> Benchmarks<C, W, E>
+impl<C, W, E> Benchmarks<C, W, E, Local>
 where
     C: Clone + Display,
     W: Clone + Display,
@@ -40,36 +68,544 @@ where
 {
     /// Create a new [Benchmarks]
     ///
+    /// The benchmark functions and hooks run on the thread that runs the suite and need not be
+    /// `Send`. Use [Benchmarks::new_threaded] to run the benchmark series concurrently.
+    ///
     /// * `name` - the name of the benchmark session
-    pub fn new(name: &str) -> Benchmarks<C, W, E> {
+    pub fn new(name: &str) -> Benchmarks<C, W, E, Local> {
+        Benchmarks::create(name)
+    }
+}
+
+impl<C, W, E, T> Benchmarks<C, W, E, T>
+where
+    C: Clone + Display,
+    W: Clone + Display,
+    Error: From<E>,
+    T: Threading,
+{
+    fn create(name: &str) -> Benchmarks<C, W, E, T> {
         Benchmarks {
             name: name.to_string(),
             names: Default::default(),
             benchmarks: vec![],
             summaries: Default::default(),
+            setup: None,
+            teardown: None,
+            continue_on_error: false,
+            isolation: Isolation::None,
+            workers: 1,
+            filter: Filter::new(),
+            skipped: vec![],
+            percentiles: DEFAULT_PERCENTILES.to_vec(),
+            csv_statistics: false,
+            retain_samples: false,
+            bootstrap: None,
+            repetition: Repetition::Fixed,
+            comparison_options: ComparisonOptions::default(),
         }
     }
 
     /// Run all benchmarks
-    pub fn run(&mut self) -> Result<(), Error> {
-        for benchmark in &self.benchmarks {
-            let summary = benchmark.run()?;
+    ///
+    /// The suite setup registered with [Self::set_setup] is performed before the first benchmark
+    /// and the suite teardown registered with [Self::set_teardown] after the last one.
+    ///
+    /// A panic in a benchmark function or in a series or workload point setup or teardown is
+    /// caught and recorded as a [crate::failure::Failure] in the [SeriesSummary], and the run
+    /// carries on with the next workload point. A panic in the suite setup or teardown is caught
+    /// and returned as an error. Panics can be caught only when the benchmark is built with
+    /// `panic = "unwind"`, which is the default.
+    ///
+    /// When the suite runs in isolated child processes, see [Self::set_isolation], and this is
+    /// a child process, only the benchmark series selected by the parent is run and the process
+    /// exits when done. The other suites of the binary are not run in a child process, their
+    /// summaries stay empty and are not saved by [Self::save_to_json] or [Self::save_to_csv].
+    pub fn run(&mut self) -> Result<(), Error>
+    where
+        T: Dispatch<C, W, E>,
+    {
+        self.workers = 1;
+        self.run_suite(Self::run_benchmarks)
+    }
+
+    fn run_suite(&mut self, run_benchmarks: fn(&mut Self) -> Result<(), Error>) -> Result<(), Error>
+    where
+        T: Dispatch<C, W, E>,
+    {
+        if let Some(selection) = isolation::selection()? {
+            if selection.suite == self.name {
+                self.run_as_child(selection);
+            }
+            return Ok(());
+        }
+        let selected = self.selected_names();
+        self.skipped = self
+            .benchmarks
+            .iter()
+            .map(|benchmark| benchmark.name().clone())
+            .filter(|name| !selected.contains(name))
+            .collect();
+        if let Some(setup) = &mut self.setup {
+            isolate(|| Ok(setup()?))?;
+        }
+        let result = run_benchmarks(self);
+        if let Some(teardown) = &mut self.teardown {
+            isolate(|| Ok(teardown()?))?;
+        }
+        result
+    }
+
+    fn run_benchmarks(&mut self) -> Result<(), Error>
+    where
+        T: Dispatch<C, W, E>,
+    {
+        for benchmark in &mut self.benchmarks {
+            if self.skipped.contains(benchmark.name()) {
+                continue;
+            }
+            let summary = match self.isolation {
+                Isolation::None => benchmark.run(self.continue_on_error)?,
+                _ => benchmark
+                    .isolated_series()
+                    .run(&self.name, self.isolation)?,
+            };
             self.summaries.insert(benchmark.name().clone(), summary);
         }
         Ok(())
     }
 
+    /// Run the benchmark series selected by the parent process, report the outcome on the
+    /// standard output and exit
+    fn run_as_child(&mut self, selection: Selection) -> !
+    where
+        T: Dispatch<C, W, E>,
+    {
+        let result = self.run_selection(selection);
+        if let Err(e) = &result {
+            isolation::report_error(e);
+        }
+        std::process::exit(if result.is_ok() { 0 } else { 1 })
+    }
+
+    fn run_selection(&mut self, selection: Selection) -> Result<(), Error>
+    where
+        T: Dispatch<C, W, E>,
+    {
+        if let Some(setup) = &mut self.setup {
+            isolate(|| Ok(setup()?))?;
+        }
+        let continue_on_error = self.continue_on_error;
+        let result = self.benchmark_mut(&selection.series).and_then(|benchmark| {
+            benchmark.run_reporting(
+                selection.points,
+                continue_on_error,
+                &mut isolation::report_fragment,
+            )
+        });
+        if let Some(teardown) = &mut self.teardown {
+            isolate(|| Ok(teardown()?))?;
+        }
+        result
+    }
+
+    /// Set the [Repetition] policy for the benchmark series `name`
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use benchmark_rs::benchmarks::Benchmarks;
+    /// use benchmark_rs::repetition::Repetition;
+    /// use benchmark_rs::stopwatch::StopWatch;
+    ///
+    /// fn example(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    ///     let _sum: u64 = (0..work).sum();
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut benchmarks = Benchmarks::new("adaptive");
+    /// benchmarks.add("sum", example, "no configuration", vec![10, 1000], 1, 1).unwrap();
+    /// // repeat until the median is known within 5% but not longer than 100 ms per point
+    /// benchmarks
+    ///     .set_repetition("sum", Repetition::adaptive(5.0, Duration::from_millis(100), 5, 1000))
+    ///     .unwrap();
+    /// benchmarks.run().unwrap();
+    /// ```
+    pub fn set_repetition(&mut self, name: &str, repetition: Repetition) -> Result<(), Error> {
+        Self::validate_repetition(&repetition)?;
+        self.benchmark_mut(name)?.set_repetition(repetition);
+        Ok(())
+    }
+
+    /// Set the [Repetition] policy for all benchmark series, [Repetition::Fixed] by default
+    ///
+    /// The policy applies to the series added so far and to the series added later, a policy set
+    /// for a single series with [Self::set_repetition] afterwards takes precedence.
+    pub fn set_default_repetition(&mut self, repetition: Repetition) -> Result<(), Error> {
+        Self::validate_repetition(&repetition)?;
+        for benchmark in &mut self.benchmarks {
+            benchmark.set_repetition(repetition.clone());
+        }
+        self.repetition = repetition;
+        Ok(())
+    }
+
+    fn validate_repetition(repetition: &Repetition) -> Result<(), Error> {
+        if let Repetition::Adaptive {
+            precision,
+            min_repeat,
+            max_repeat,
+            ..
+        } = *repetition
+        {
+            if min_repeat == 0 || max_repeat < min_repeat {
+                return Err(anyhow!(
+                    "Invalid repetition bounds: min_repeat: {}, max_repeat: {}",
+                    min_repeat,
+                    max_repeat
+                ));
+            } else if precision.is_nan() || precision <= 0.0 {
+                return Err(anyhow!("Invalid repetition precision: {}", precision));
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the [OutlierPolicy] for the benchmark series `name`
+    ///
+    /// Outliers are counted for each workload point and reported by [crate::run_summary::RunSummary::outliers]. By
+    /// default they are included in the reported statistics. Excluded outliers are kept in the
+    /// retained samples, but [Self::analyze] leaves them out when it compares samples.
+    pub fn set_outlier_policy(
+        &mut self,
+        name: &str,
+        outlier_policy: OutlierPolicy,
+    ) -> Result<(), Error> {
+        self.benchmark_mut(name)?.set_outlier_policy(outlier_policy);
+        Ok(())
+    }
+
+    /// Run the benchmark series `name` in batches
+    ///
+    /// Each sample invokes the benchmark function repeatedly with the same [StopWatch], so that
+    /// the overhead of the measurement does not dominate the measured time of functions that run
+    /// for nanoseconds. The number of invocations in a batch is chosen before the measurement of
+    /// each workload point, so that a sample lasts at least `min_sample_duration`. The durations
+    /// reported in [crate::run_summary::RunSummary] are durations of a single invocation.
+    ///
+    /// The arguments of all invocations in a batch are prepared before the stopwatch is started,
+    /// use [Self::add_by_ref] to avoid keeping a clone of the configuration for each invocation.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use benchmark_rs::benchmarks::Benchmarks;
+    /// use benchmark_rs::stopwatch::StopWatch;
+    ///
+    /// fn hash(_stop_watch: &mut StopWatch, _config: &&str, work: &u64) -> Result<(), anyhow::Error> {
+    ///     let _hash = work.wrapping_mul(0x9E3779B97F4A7C15).rotate_left(5);
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut benchmarks = Benchmarks::new("batches");
+    /// benchmarks.add_by_ref("hash", hash, "no configuration", vec![1, 2, 3], 10, 1).unwrap();
+    /// benchmarks.set_batching("hash", Duration::from_micros(100)).unwrap();
+    /// benchmarks.run().unwrap();
+    /// ```
+    pub fn set_batching(&mut self, name: &str, min_sample_duration: Duration) -> Result<(), Error> {
+        if min_sample_duration.is_zero() {
+            Err(anyhow!("Cannot batch for zero sample duration"))
+        } else {
+            self.benchmark_mut(name)?.set_batching(min_sample_duration);
+            Ok(())
+        }
+    }
+
+    /// Continue running the suite when a benchmark fails
+    ///
+    /// By default the first error returned by a benchmark function, setup or teardown stops the
+    /// run and is returned by [Self::run]. When `continue_on_error` is set, the error is recorded
+    /// as a [crate::failure::Failure] in the [SeriesSummary] and the run carries on with the next
+    /// workload point. Failed points are reported in the JSON and CSV outputs and by
+    /// [Self::analyze].
+    pub fn set_continue_on_error(&mut self, continue_on_error: bool) {
+        self.continue_on_error = continue_on_error;
+    }
+
+    /// Set the percentiles computed for each workload point of all benchmark series
+    ///
+    /// The percentiles are reported by [crate::run_summary::RunSummary::percentiles] and are between 0 and 100. The
+    /// default percentiles are 5, 25, 75, 90, 95 and 99.
+    pub fn set_percentiles(&mut self, percentiles: &[f64]) -> Result<(), Error> {
+        if let Some(percentile) = percentiles
+            .iter()
+            .find(|percentile| !(0.0..=100.0).contains(*percentile))
+        {
+            Err(anyhow!("Percentile out of range: {}", percentile))
+        } else {
+            self.percentiles = percentiles.to_vec();
+            for benchmark in &mut self.benchmarks {
+                benchmark.set_percentiles(self.percentiles.clone());
+            }
+            Ok(())
+        }
+    }
+
+    /// Add the mean, percentiles, interquartile range, median absolute deviation and coefficient
+    /// of variation columns to the CSV output of [Self::summary_as_csv] and [Self::save_to_csv]
+    pub fn set_csv_statistics(&mut self, csv_statistics: bool) {
+        self.csv_statistics = csv_statistics;
+    }
+
+    /// Keep the measured samples of each workload point in the [Summary]
+    ///
+    /// The samples are reported by [crate::run_summary::RunSummary::raw_samples] and saved with the JSON output.
+    /// [Self::analyze] compares the medians of the samples when both summaries retained them.
+    pub fn set_retain_samples(&mut self, retain_samples: bool) {
+        self.retain_samples = retain_samples;
+        for benchmark in &mut self.benchmarks {
+            benchmark.set_retain_samples(retain_samples);
+        }
+    }
+
+    /// Compute bootstrap confidence intervals of the median and of the mean of each workload point
+    ///
+    /// The intervals are reported by [crate::run_summary::RunSummary::median_ci] and
+    /// [crate::run_summary::RunSummary::mean_ci], saved with the JSON output and added to the
+    /// statistics columns of the CSV output, see [Self::set_csv_statistics]. With
+    /// [Self::set_confidence_intervals] [Self::analyze] considers a workload point unchanged when
+    /// the intervals of both runs overlap. Pass `None` to stop computing the intervals.
+    pub fn set_bootstrap(&mut self, bootstrap: Option<Bootstrap>) -> Result<(), Error> {
+        if let Some(bootstrap) = bootstrap {
+            if !(bootstrap.confidence() > 0.0 && bootstrap.confidence() < 100.0) {
+                return Err(anyhow!(
+                    "Confidence level out of range: {}",
+                    bootstrap.confidence()
+                ));
+            } else if bootstrap.resamples() == 0 {
+                return Err(anyhow!("Cannot bootstrap with 0 resamples"));
+            }
+        }
+        self.bootstrap = bootstrap;
+        for benchmark in &mut self.benchmarks {
+            benchmark.set_bootstrap(bootstrap);
+        }
+        Ok(())
+    }
+
+    /// Consider a workload point unchanged in [Self::analyze] when the confidence intervals of the
+    /// compared median or mean of both runs overlap, disabled by default
+    ///
+    /// The intervals are computed when bootstrapping is enabled, see [Self::set_bootstrap]. See
+    /// [ComparisonOptions::set_confidence_intervals].
+    pub fn set_confidence_intervals(&mut self, confidence_intervals: bool) {
+        self.comparison_options
+            .set_confidence_intervals(confidence_intervals);
+    }
+
+    /// Set the [Metric] compared by [Self::analyze], the median by default
+    ///
+    /// See [ComparisonOptions::set_metric].
+    pub fn set_comparison_metric(&mut self, metric: Metric) -> Result<(), Error> {
+        self.comparison_options.set_metric(metric)
+    }
+
+    /// Set the thresholds of individual series and workload points used by [Self::analyze]
+    ///
+    /// Workload points without a rule in `threshold_policy` are compared with the threshold
+    /// passed to [Self::analyze]. See [ThresholdPolicy].
+    pub fn set_threshold_policy(&mut self, threshold_policy: ThresholdPolicy) {
+        self.comparison_options
+            .set_threshold_policy(threshold_policy);
+    }
+
+    /// Compare workload points in [Self::analyze] with a statistical test of their samples
+    ///
+    /// A workload point is considered changed only when the change exceeds the threshold and the
+    /// difference is significant at `significance_level`. The test needs the samples of both
+    /// runs, see [Self::set_retain_samples], and the threshold alone decides for summaries
+    /// without samples. See [ComparisonOptions::set_significance_test].
+    pub fn set_significance_test(
+        &mut self,
+        significance_test: Option<SignificanceTest>,
+        significance_level: f64,
+    ) -> Result<(), Error> {
+        self.comparison_options
+            .set_significance_test(significance_test, significance_level)
+    }
+
+    /// Run only the benchmark series selected by `filter`
+    ///
+    /// The series that are not selected are skipped by [Self::run] and [Self::run_parallel]. The
+    /// [Summary] records the filter and the skipped series.
+    ///
+    /// ```
+    /// use benchmark_rs::benchmarks::Benchmarks;
+    /// use benchmark_rs::filter::{Filter, NamePattern};
+    /// use benchmark_rs::stopwatch::StopWatch;
+    ///
+    /// fn example(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    ///     let _sum: u64 = (0..work).sum();
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut benchmarks = Benchmarks::new("filtered");
+    /// benchmarks.add("sum small", example, "small", vec![1, 2, 3], 2, 1).unwrap();
+    /// benchmarks.add("sum large", example, "large", vec![1000, 2000], 2, 1).unwrap();
+    /// benchmarks.set_tags("sum large", &["slow"]).unwrap();
+    ///
+    /// let mut filter = Filter::new();
+    /// filter.add_name(NamePattern::glob("sum *"));
+    /// filter.add_tag("slow");
+    /// benchmarks.set_filter(filter);
+    /// benchmarks.run().unwrap();
+    /// assert_eq!(benchmarks.summary().skipped(), &vec!["sum small".to_string()]);
+    /// ```
+    pub fn set_filter(&mut self, filter: Filter) {
+        self.filter = filter;
+    }
+
+    /// Assign tags to the benchmark series `name`, see [Filter::add_tag]
+    pub fn set_tags(&mut self, name: &str, tags: &[&str]) -> Result<(), Error> {
+        self.benchmark_mut(name)?
+            .set_tags(tags.iter().map(|tag| tag.to_string()).collect());
+        Ok(())
+    }
+
+    /// Run the benchmarks in isolated child processes
+    ///
+    /// With [Isolation::Series] or [Isolation::Point], [Self::run] re-executes the current binary
+    /// with the same arguments once per benchmark series or once per workload point. The child
+    /// runs only the selected benchmark series or workload point and streams the outcome of each
+    /// workload point back to the parent over its standard output, and the parent assembles the
+    /// [Summary] as if the benchmarks were run in process. Output of the child that is not part
+    /// of this protocol is forwarded to the standard output of the parent.
+    ///
+    /// The binary must build the same suites with the same names in the same order in the parent
+    /// and in the children, the suite setup and teardown are performed in each child as well as in
+    /// the parent, and the series setup and teardown in each child. Suites with other names are
+    /// not run in a child process.
+    ///
+    /// A child that exits without reporting, for example on abort, is recorded as a
+    /// [crate::failure::Failure] of kind [crate::failure::FailureKind::Crash]. When a point
+    /// timeout is set with [Self::set_point_timeout], a child that runs a workload point longer
+    /// than the timeout is killed. In both cases the run resumes with the next workload point in
+    /// a new child process.
+    pub fn set_isolation(&mut self, isolation: Isolation) {
+        self.isolation = isolation;
+    }
+
+    /// Limit the duration of a single iteration of the benchmark series `name`
+    ///
+    /// The limit applies to the wall clock time of an invocation of the benchmark function,
+    /// including its setup but not its teardown, see [Self::set_point_teardown] and
+    /// [Self::add_with_setup_and_teardown]. For batches it applies to the average time of the
+    /// invocations in a batch. When an iteration exceeds the limit, the workload point is
+    /// recorded as a [crate::failure::Failure] of kind [crate::failure::FailureKind::Timeout], its
+    /// remaining iterations are skipped and the run carries on with the next workload point.
+    ///
+    /// In a suite created with [Benchmarks::new_threaded] the benchmark function is invoked on a
+    /// worker thread, and an invocation that does not return in time is abandoned, see
+    /// [crate::threading::Dispatch]. The point teardown is not performed for an abandoned
+    /// invocation, and a closure is not invoked again until the abandoned invocation returns. The
+    /// next workload point waits for it as long as its own timeouts allow, and when it does not
+    /// return the remaining workload points are not run and the series is recorded as a single
+    /// failure without a workload point. In other suites the limit is checked when the
+    /// invocation returns, use [Self::set_point_timeout] with [Self::set_isolation] to kill hung
+    /// benchmarks.
+    pub fn set_iteration_timeout(&mut self, name: &str, timeout: Duration) -> Result<(), Error> {
+        self.benchmark_mut(name)?.set_iteration_timeout(timeout);
+        Ok(())
+    }
+
+    /// Limit the duration of each workload point of the benchmark series `name`
+    ///
+    /// The limit applies to the wall clock time of all iterations of a workload point, ramp up
+    /// included and teardowns excluded, as for [Self::set_iteration_timeout]. When a workload point exceeds the limit it is recorded as a
+    /// [crate::failure::Failure] of kind [crate::failure::FailureKind::Timeout], its remaining
+    /// iterations are skipped and the run carries on with the next workload point.
+    ///
+    /// In a suite created with [Benchmarks::new_threaded] an invocation that does not return
+    /// before the limit is abandoned, as described in [Self::set_iteration_timeout]. In other
+    /// suites the limit is checked after each iteration, and an invocation that never returns can
+    /// be interrupted only when the benchmarks run in child processes, see [Self::set_isolation].
+    pub fn set_point_timeout(&mut self, name: &str, timeout: Duration) -> Result<(), Error> {
+        self.benchmark_mut(name)?.set_point_timeout(timeout);
+        Ok(())
+    }
+
+    /// Set the suite setup, performed once before any benchmark is run
+    pub fn set_setup<F>(&mut self, f: F)
+    where
+        F: FnMut() -> Result<(), E> + 'static,
+    {
+        self.setup = Some(Box::new(f));
+    }
+
+    /// Set the suite teardown, performed once after all benchmarks were run
+    pub fn set_teardown<F>(&mut self, f: F)
+    where
+        F: FnMut() -> Result<(), E> + 'static,
+    {
+        self.teardown = Some(Box::new(f));
+    }
+
+    /// Set the series setup, performed once before the first workload point of the benchmark
+    /// series `name`
+    pub fn set_series_setup<F>(&mut self, name: &str, f: F) -> Result<(), Error>
+    where
+        F: FnMut(&C) -> Result<(), E> + Movable<T>,
+    {
+        self.benchmark_mut(name)?
+            .set_series_setup(f.into_series_hook());
+        Ok(())
+    }
+
+    /// Set the series teardown, performed once after the last workload point of the benchmark
+    /// series `name`
+    pub fn set_series_teardown<F>(&mut self, name: &str, f: F) -> Result<(), Error>
+    where
+        F: FnMut(&C) -> Result<(), E> + Movable<T>,
+    {
+        self.benchmark_mut(name)?
+            .set_series_teardown(f.into_series_hook());
+        Ok(())
+    }
+
+    /// Set the workload point teardown for the benchmark series `name`
+    ///
+    /// The teardown is performed right after each invocation of the benchmark function, while
+    /// the stopwatch is stopped, also within a batch of invocations, see [Self::set_batching]. Its
+    /// duration is excluded from the timeouts. It receives the configuration and the workload
+    /// point, see [Self::add_with_setup_and_teardown] for a teardown that receives the input
+    /// produced by the setup.
+    pub fn set_point_teardown<F>(&mut self, name: &str, f: F) -> Result<(), Error>
+    where
+        F: FnMut(&C, &W) -> Result<(), E> + Movable<T>,
+    {
+        self.benchmark_mut(name)?
+            .set_point_teardown(f.into_point_hook());
+        Ok(())
+    }
+
+    fn benchmark_mut(&mut self, name: &str) -> Result<&mut Benchmark<C, W, E, T>, Error> {
+        self.benchmarks
+            .iter_mut()
+            .find(|benchmark| benchmark.name() == name)
+            .ok_or_else(|| anyhow!("Benchmark not found: {}", name))
+    }
+
     /// Create and add a Benchmark
     ///
     /// * `name` - the name of the benchmark series. The result will be accessible by the name as
-    /// a key from the summary.
-    /// * `f` - the function that runs the benchmark.
+    ///   a key from the summary.
+    /// * `f` - the function that runs the benchmark. The configuration and the workload point are
+    ///   cloned before the stopwatch is started, see [Self::add_by_ref] to avoid the clone
+    ///   altogether.
     /// * `config` - the configuration value for this benchmark series
     /// * `work` - workload points vector for this benchmark series. Elements of this vector are
-    /// passed to `f` in each iteration
+    ///   passed to `f` in each iteration
     /// * `repeat` - number of times the benchmark will be repeated
     /// * `ramp_up` - number of times the benchmark will be performed before the measurement is
-    /// taken
+    ///   taken
     ///
     pub fn add(
         &mut self,
@@ -79,31 +615,256 @@ where
         work: Vec<W>,
         repeat: usize,
         ramp_up: usize,
+    ) -> Result<(), Error> {
+        self.push(
+            name,
+            BenchmarkFn::Function(f),
+            config,
+            work,
+            repeat,
+            ramp_up,
+        )
+    }
+
+    /// Create and add a Benchmark that runs a closure
+    ///
+    /// Same as [Self::add] but accepts any `FnMut`, so the benchmark can capture state that was
+    /// built once before the suite is run, like connection pools or loaded fixtures.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use benchmark_rs::benchmarks::Benchmarks;
+    /// use benchmark_rs::stopwatch::StopWatch;
+    ///
+    /// let fixture: Arc<Vec<u64>> = Arc::new((0..1000).collect());
+    /// let mut benchmarks = Benchmarks::new("closures");
+    /// benchmarks
+    ///     .add_closure(
+    ///         "sum prefix",
+    ///         move |_stop_watch: &mut StopWatch, _config: &str, work: usize| {
+    ///             let _sum: u64 = fixture.iter().take(work).sum();
+    ///             Ok::<(), anyhow::Error>(())
+    ///         },
+    ///         "no configuration",
+    ///         vec![10, 100, 1000],
+    ///         2,
+    ///         1,
+    ///     )
+    ///     .expect("failed to add benchmark");
+    /// benchmarks.run().expect("failed to run benchmarks");
+    /// ```
+    pub fn add_closure<F>(
+        &mut self,
+        name: &str,
+        f: F,
+        config: C,
+        work: Vec<W>,
+        repeat: usize,
+        ramp_up: usize,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(&mut StopWatch, C, W) -> Result<(), E> + Movable<T>,
+    {
+        self.push(
+            name,
+            BenchmarkFn::Boxed(Some(f.into_by_value())),
+            config,
+            work,
+            repeat,
+            ramp_up,
+        )
+    }
+
+    /// Create and add a Benchmark that receives the configuration and the workload point by
+    /// reference
+    ///
+    /// Same as [Self::add_closure] but `f` borrows the configuration and the workload point
+    /// instead of receiving a clone in each iteration. Use it when `C` or `W` are expensive to
+    /// clone.
+    ///
+    /// ```
+    /// use benchmark_rs::benchmarks::Benchmarks;
+    /// use benchmark_rs::stopwatch::StopWatch;
+    ///
+    /// fn lookup(_stop_watch: &mut StopWatch, config: &String, work: &usize) -> Result<(), anyhow::Error> {
+    ///     let _count = config.chars().take(*work).filter(|c| c.is_alphabetic()).count();
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut benchmarks = Benchmarks::new("by-reference");
+    /// benchmarks
+    ///     .add_by_ref("lookup", lookup, "a".repeat(1_000_000), vec![10, 1000, 100_000], 2, 1)
+    ///     .expect("failed to add benchmark");
+    /// benchmarks.run().expect("failed to run benchmarks");
+    /// ```
+    pub fn add_by_ref<F>(
+        &mut self,
+        name: &str,
+        f: F,
+        config: C,
+        work: Vec<W>,
+        repeat: usize,
+        ramp_up: usize,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(&mut StopWatch, &C, &W) -> Result<(), E> + Movable<T>,
+    {
+        self.push(
+            name,
+            BenchmarkFn::Boxed(Some(f.into_by_reference())),
+            config,
+            work,
+            repeat,
+            ramp_up,
+        )
+    }
+
+    /// Create and add a Benchmark with a workload point setup
+    ///
+    /// Same as [Self::add_closure] but the input of `f` is produced by `setup`. The setup is
+    /// performed before each invocation of `f` while the stopwatch is stopped. It receives the
+    /// configuration and the workload point and produces the input passed to `f`, so the
+    /// preparation of the input does not reflect in the measurement.
+    ///
+    /// ```
+    /// use benchmark_rs::benchmarks::Benchmarks;
+    /// use benchmark_rs::stopwatch::StopWatch;
+    ///
+    /// fn sort(_stop_watch: &mut StopWatch, _config: &str, mut input: Vec<u64>) -> Result<(), anyhow::Error> {
+    ///     input.sort();
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut benchmarks = Benchmarks::new("setup");
+    /// benchmarks
+    ///     .add_with_setup(
+    ///         "sort",
+    ///         // produce a fresh unsorted vector for every invocation
+    ///         |_config: &&str, work: &u64| Ok((0..*work).rev().collect()),
+    ///         sort,
+    ///         "no configuration",
+    ///         vec![10, 100, 1000],
+    ///         2,
+    ///         1,
+    ///     )
+    ///     .expect("failed to add benchmark");
+    /// benchmarks.run().expect("failed to run benchmarks");
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_with_setup<I, S, F>(
+        &mut self,
+        name: &str,
+        setup: S,
+        f: F,
+        config: C,
+        work: Vec<W>,
+        repeat: usize,
+        ramp_up: usize,
+    ) -> Result<(), Error>
+    where
+        S: FnMut(&C, &W) -> Result<I, E>,
+        F: FnMut(&mut StopWatch, C, I) -> Result<(), E>,
+        (S, F): Movable<T>,
+    {
+        self.push(
+            name,
+            BenchmarkFn::Boxed(Some((setup, f).into_staged())),
+            config,
+            work,
+            repeat,
+            ramp_up,
+        )
+    }
+
+    /// Create and add a Benchmark with a workload point setup and teardown
+    ///
+    /// Same as [Self::add_with_setup] but `f` borrows the input, and the input is passed to
+    /// `teardown` right after each invocation of `f`, while the stopwatch is stopped. The time
+    /// spent in the teardown is excluded from the timeouts, see [Self::set_iteration_timeout].
+    ///
+    /// ```
+    /// use benchmark_rs::benchmarks::Benchmarks;
+    /// use benchmark_rs::stopwatch::StopWatch;
+    /// use std::fs;
+    /// use std::path::PathBuf;
+    ///
+    /// let mut benchmarks = Benchmarks::new("setup and teardown");
+    /// benchmarks
+    ///     .add_with_setup_and_teardown(
+    ///         "write",
+    ///         |dir: &String, work: &usize| Ok(PathBuf::from(dir).join(format!("write-{work}.txt"))),
+    ///         |_stop_watch: &mut StopWatch, _dir: String, path: &mut PathBuf| {
+    ///             fs::write(path, "benchmark")
+    ///         },
+    ///         // remove the file written by the invocation
+    ///         |_dir: &String, _work: &usize, path: PathBuf| fs::remove_file(path),
+    ///         std::env::temp_dir().display().to_string(),
+    ///         vec![1, 2],
+    ///         2,
+    ///         0,
+    ///     )
+    ///     .expect("failed to add benchmark");
+    /// benchmarks.run().expect("failed to run benchmarks");
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_with_setup_and_teardown<I, S, F, D>(
+        &mut self,
+        name: &str,
+        setup: S,
+        f: F,
+        teardown: D,
+        config: C,
+        work: Vec<W>,
+        repeat: usize,
+        ramp_up: usize,
+    ) -> Result<(), Error>
+    where
+        S: FnMut(&C, &W) -> Result<I, E>,
+        F: FnMut(&mut StopWatch, C, &mut I) -> Result<(), E>,
+        D: FnMut(&C, &W, I) -> Result<(), E>,
+        (S, F, D): Movable<T>,
+    {
+        self.push(
+            name,
+            BenchmarkFn::Boxed(Some((setup, f, teardown).into_staged())),
+            config,
+            work,
+            repeat,
+            ramp_up,
+        )?;
+        self.benchmark_mut(name)?.set_releases_input();
+        Ok(())
+    }
+
+    fn push(
+        &mut self,
+        name: &str,
+        f: BenchmarkFn<C, W, E, T>,
+        config: C,
+        work: Vec<W>,
+        repeat: usize,
+        ramp_up: usize,
     ) -> Result<(), Error> {
         let exists = !self.names.insert(name.to_string());
         if exists {
-            Err(anyhow!(
-                "Benchmark with identical name exists: {}",
-                name.to_string()
-            ))
+            Err(anyhow!("Benchmark with identical name exists: {}", name))
         } else if repeat == 0 {
             Err(anyhow!("Cannot benchmark 0 runs"))
         } else {
-            self.benchmarks.push(Benchmark::new(
-                name.to_string(),
-                f,
-                config,
-                work,
-                repeat,
-                ramp_up,
-            ));
+            let mut benchmark = Benchmark::new(name.to_string(), f, config, work, repeat, ramp_up);
+            benchmark.set_percentiles(self.percentiles.clone());
+            benchmark.set_retain_samples(self.retain_samples);
+            benchmark.set_bootstrap(self.bootstrap);
+            benchmark.set_repetition(self.repetition.clone());
+            self.benchmarks.push(benchmark);
             Ok(())
         }
     }
 
     /// Produce [Summary] for all series
     pub fn summary(&self) -> Summary {
-        let mut summary = Summary::new(self.name().clone());
+        let mut summary = Summary::new(self.name().clone(), self.workers);
+        summary.set_filter(self.filter.clone(), self.skipped.clone());
         for (name, series_summary) in &self.summaries {
             summary.add(name.clone(), series_summary.clone());
         }
@@ -125,7 +886,10 @@ where
     ) -> HashMap<String, Vec<String>> {
         let mut result = HashMap::new();
         for (name, summary) in &self.summaries {
-            result.insert(name.clone(), summary.as_csv(with_headers, with_config));
+            result.insert(
+                name.clone(),
+                summary.as_csv(with_headers, with_config, self.csv_statistics),
+            );
         }
         result
     }
@@ -137,6 +901,8 @@ where
     /// * `with_headers` - add column headers on the first line
     /// * `with_config` - add the configuration string in the headers row
     ///
+    /// Nothing is saved in an isolated child process, see [Self::run].
+    ///
     /// ```
     /// use std::path::PathBuf;
     /// use benchmark_rs::benchmarks::Benchmarks;
@@ -149,13 +915,16 @@ where
         with_headers: bool,
         with_config: bool,
     ) -> Result<(), anyhow::Error> {
+        if isolation::in_child() {
+            return Ok(());
+        }
         if !dir.exists() {
             create_dir_all(&dir)?;
         }
         let series_csv = self.summary_as_csv(with_headers, with_config);
         for (name, series) in series_csv {
-            let mut results_path = dir.join(name.clone());
-            results_path.set_extension("csv");
+            // appended, so that a name with a dot keeps its last part
+            let results_path = dir.join(format!("{name}.csv"));
             let mut results_writer = BufWriter::new(
                 File::create(&results_path)
                     .with_context(|| anyhow!("path: {}", results_path.to_string_lossy()))?,
@@ -170,7 +939,8 @@ where
     /// Save the summary to a json file.
     ///
     /// The name of the JSON file is the name of the suite of benchmarks.
-    /// If dir doesn't exist - create it.
+    /// If dir doesn't exist - create it. Nothing is saved in an isolated child process, see
+    /// [Self::run].
     /// ```
     /// use std::path::PathBuf;
     /// use anyhow::anyhow;
@@ -179,11 +949,13 @@ where
     /// benchmarks.save_to_json(PathBuf::from("./target/benchmarks")).expect("failed to save to json");
     /// ```
     pub fn save_to_json(&self, dir: PathBuf) -> Result<(), anyhow::Error> {
+        if isolation::in_child() {
+            return Ok(());
+        }
         if !dir.exists() {
             create_dir_all(&dir)?;
         }
-        let mut results_path = dir.join(self.name());
-        results_path.set_extension("json");
+        let results_path = dir.join(format!("{}.json", self.name()));
         let mut writer = BufWriter::new(
             File::create(&results_path)
                 .with_context(|| anyhow!("path: {}", results_path.to_string_lossy()))?,
@@ -197,87 +969,36 @@ where
     pub fn configs(&self) -> HashMap<String, String> {
         let mut result = HashMap::new();
         for (name, summary) in &self.summaries {
-            result.insert(name.clone(), summary.config());
+            result.insert(name.clone(), summary.config().clone());
         }
         result
     }
 
+    /// Names of the series selected by the [Filter], in the order they were added
+    pub fn selected_names(&self) -> Vec<String> {
+        self.benchmarks
+            .iter()
+            .filter(|benchmark| self.filter.matches(benchmark.name(), benchmark.tags()))
+            .map(|benchmark| benchmark.name().clone())
+            .collect()
+    }
+
     ///  The benchmarks suite name
     pub fn name(&self) -> &String {
         &self.name
     }
 
-    fn compare_median(
-        point: &str,
-        current: u64,
-        previous: u64,
-        threshold: f64,
-    ) -> BenchmarkComparison {
-        let change = (current as f64 / (previous as f64 / 100.0)) - 100.0;
-        let point = point.to_owned();
-        if (current == previous) || (change.abs() <= threshold.abs()) {
-            BenchmarkComparison::Equal {
-                point,
-                previous,
-                current,
-                change,
-            }
-        } else if change < 0.0 {
-            BenchmarkComparison::Less {
-                point,
-                previous,
-                current,
-                change,
-            }
-        } else {
-            BenchmarkComparison::Greater {
-                point,
-                previous,
-                current,
-                change,
-            }
-        }
-    }
-
-    fn compare_series(
-        current_series: &[(String, RunSummary)],
-        previous_series: &[(String, RunSummary)],
-        threshold: f64,
-    ) -> Result<HashMap<String, BenchmarkComparison>, Error> {
-        let current_points: Vec<String> = current_series
-            .iter()
-            .map(|(point, _run_summary)| point.clone())
-            .collect();
-        let previous_points: Vec<String> = previous_series
-            .iter()
-            .map(|(point, _run_summary)| point.clone())
-            .collect();
-
-        if current_points.is_empty() || previous_points.is_empty() {
-            Err(anyhow!("Can compare only non empty series"))
-        } else if current_points != previous_points {
-            Err(anyhow!(
-                "Can compare series with identical workload points only"
-            ))
-        } else {
-            let mut comparisons = HashMap::new();
-            for i in 0..current_series.len() {
-                let point = current_series[i].0.clone();
-                let comparison = Self::compare_median(
-                    point.as_str(),
-                    current_series[i].1.median_nanos(),
-                    previous_series[i].1.median_nanos(),
-                    threshold,
-                );
-
-                comparisons.insert(point, comparison);
-            }
-            Ok(comparisons)
-        }
-    }
-
     /// Compare the current result against a previous result.
     ///
+    /// Workload points that failed in either run are not compared, failures of the current run
+    /// are reported in [AnalysisResult::failed_series].
+    ///
+    /// By default a workload point is considered unchanged when the change of its median is
+    /// within `threshold`. The comparison is configured with [Self::set_comparison_metric],
+    /// [Self::set_threshold_policy], [Self::set_confidence_intervals] and
+    /// [Self::set_significance_test]; overlapping confidence intervals are not considered unless
+    /// enabled.
+    ///
     /// * `prev_result_string_opt` - a JSON string of the [Summary] of previous run
     /// * `threshold` - threshold used to determine equality.
     pub fn analyze(
@@ -285,37 +1006,122 @@ where
         prev_result_string_opt: Option<String>,
         threshold: f64,
     ) -> Result<AnalysisResult, Error> {
-        let current_summary = self.summary();
         let prev_summary = match prev_result_string_opt {
-            None => Summary::new(self.name().clone()),
-            Some(prev_result_string) => {
-                serde_json::from_str::<Summary>(prev_result_string.as_str())?
-            }
+            None => Summary::new(self.name().clone(), self.workers),
+            Some(prev_result_string) => Summary::from_json(prev_result_string.as_str())?,
         };
-        if current_summary.name() != prev_summary.name() {
-            Err(anyhow!(
-                "Comparing differently named benchmarks.rs: {} <=> {}",
-                current_summary.name(),
-                prev_summary.name()
-            ))
+        self.compare(&prev_summary, threshold)
+    }
+
+    /// Compare the current result against a previous result saved to a JSON file, see
+    /// [Self::analyze] and [Self::save_to_json]
+    ///
+    /// * `path` - the JSON file with the [Summary] of the previous run
+    /// * `threshold` - threshold used to determine equality.
+    pub fn analyze_against_file(
+        &self,
+        path: &Path,
+        threshold: f64,
+    ) -> Result<AnalysisResult, Error> {
+        self.compare(&Summary::from_json_file(path)?, threshold)
+    }
+
+    fn compare(&self, previous: &Summary, threshold: f64) -> Result<AnalysisResult, Error> {
+        let mut options = self.comparison_options.clone();
+        options.set_threshold(threshold);
+        comparison::compare_summaries(&self.summary(), previous, &options)
+    }
+}
+
+impl<C, W, E> Benchmarks<C, W, E, Threaded>
+where
+    C: Clone + Display + Send,
+    W: Clone + Display + Send,
+    Error: From<E>,
+{
+    /// Create a new [Benchmarks] that can run its benchmark series concurrently with
+    /// [Self::run_parallel]
+    ///
+    /// Same as [Benchmarks::new] but the benchmark functions and the series and workload point
+    /// hooks must be `Send`.
+    ///
+    /// * `name` - the name of the benchmark session
+    pub fn new_threaded(name: &str) -> Benchmarks<C, W, E, Threaded> {
+        Benchmarks::create(name)
+    }
+
+    /// Run all benchmarks, running up to `workers` benchmark series concurrently
+    ///
+    /// Same as [Self::run] but the benchmark series are distributed between `workers` threads.
+    /// Each series still runs its workload points one after another in a single thread. When a
+    /// benchmark fails and the suite does not continue on error, the series already running are
+    /// completed, no new series is started and the first error in the order the series were
+    /// added is returned.
+    ///
+    /// Concurrent series compete for the CPU, caches and memory bandwidth, so the [Summary]
+    /// records the number of workers and [Self::analyze] refuses to compare summaries produced
+    /// with different numbers of workers.
+    ///
+    /// Available for suites created with [Self::new_threaded].
+    pub fn run_parallel(&mut self, workers: usize) -> Result<(), Error>
+    where
+        E: Send,
+        Threaded: Dispatch<C, W, E>,
+    {
+        if workers == 0 {
+            Err(anyhow!("Cannot run benchmarks with 0 workers"))
         } else {
-            let mut analysis_result = AnalysisResult::new(current_summary.name().clone());
-            for (name, current_series_summary) in current_summary.series() {
-                match prev_summary.series().get(name) {
-                    None => {
-                        analysis_result.add_new(name.clone());
-                    }
-                    Some(prev_series_summary) => {
-                        let comparisons = Self::compare_series(
-                            current_series_summary.runs(),
-                            prev_series_summary.runs(),
-                            threshold,
-                        )?;
-                        analysis_result.add(name.clone(), comparisons);
+            self.workers = workers;
+            self.run_suite(Self::run_benchmarks_parallel)
+        }
+    }
+
+    fn run_benchmarks_parallel(&mut self) -> Result<(), Error>
+    where
+        E: Send,
+        Threaded: Dispatch<C, W, E>,
+    {
+        let (suite, isolation, continue_on_error, skipped) = (
+            &self.name,
+            self.isolation,
+            self.continue_on_error,
+            &self.skipped,
+        );
+        let queue = Mutex::new(
+            self.benchmarks
+                .iter_mut()
+                .enumerate()
+                .filter(|(_i, benchmark)| !skipped.contains(benchmark.name())),
+        );
+        let stopped = AtomicBool::new(false);
+        let results = Mutex::new(Vec::new());
+        thread::scope(|scope| {
+            for _worker in 0..self.workers {
+                scope.spawn(|| {
+                    while !stopped.load(Ordering::SeqCst) {
+                        let Some((i, benchmark)) = queue.lock().unwrap().next() else {
+                            break;
+                        };
+                        let result = match isolation {
+                            Isolation::None => benchmark.run(continue_on_error),
+                            _ => benchmark.isolated_series().run(suite, isolation),
+                        };
+                        if result.is_err() {
+                            stopped.store(true, Ordering::SeqCst);
+                        }
+                        results
+                            .lock()
+                            .unwrap()
+                            .push((i, benchmark.name().clone(), result));
                     }
-                }
+                });
             }
-            Ok(analysis_result)
+        });
+        let mut results = results.into_inner().unwrap();
+        results.sort_by_key(|(i, _name, _result)| *i);
+        for (_i, name, result) in results {
+            self.summaries.insert(name, result?);
         }
+        Ok(())
     }
 }
@@ -0,0 +1,256 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use anyhow::{anyhow, Error};
+use benchmark_rs::analysis_result::AnalysisResult;
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::comparison::{compare_summaries, ComparisonOptions};
+use benchmark_rs::harness::{parse_args, ComparisonArgs};
+use benchmark_rs::summary::Summary;
+
+const USAGE: &str = "\
+Usage: benchmark-rs compare <PREVIOUS> <CURRENT> [OPTIONS]
+
+Compare two summaries saved with Benchmarks::save_to_json
+
+Arguments:
+  <PREVIOUS>                  Summary of the previous run
+  <CURRENT>                   Summary of the current run
+
+Options:";
+
+const USAGE_FOOTER: &str =
+    "  --format <FORMAT>           Output format, text or json [default: text]
+  -h, --help                  Print help
+
+Exit status is 0 when no regression was found, 1 when a regression was found, when the current
+run recorded failures, when workload points could not be compared by the metric, or with
+--fail-on-removed when series or workload points were removed, and 2 on error.";
+
+fn usage() -> String {
+    format!("{}\n{}\n{}", USAGE, ComparisonArgs::USAGE, USAGE_FOOTER)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct CompareArgs {
+    previous: String,
+    current: String,
+    comparison: ComparisonArgs,
+    options: ComparisonOptions,
+    format: Format,
+}
+
+impl CompareArgs {
+    fn parse(args: &[String]) -> Result<CompareArgs, Error> {
+        let mut comparison = ComparisonArgs::default();
+        let mut format = Format::Text;
+        let paths = parse_args(args.iter().cloned(), |flag, value| match flag {
+            "--format" => {
+                format = match value()?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    format => return Err(anyhow!("Unknown format: {}", format)),
+                };
+                Ok(true)
+            }
+            flag => comparison.parse_option(flag, value),
+        })?;
+        let options = comparison.comparison_options()?;
+        match <[String; 2]>::try_from(paths) {
+            Ok([previous, current]) => Ok(CompareArgs {
+                previous,
+                current,
+                comparison,
+                options,
+                format,
+            }),
+            Err(_) => Err(anyhow!(
+                "Expected the previous and the current summary files"
+            )),
+        }
+    }
+}
+
+fn compare(args: &CompareArgs) -> Result<AnalysisResult, Error> {
+    let previous = Summary::from_json_file(Path::new(&args.previous))?;
+    let current = Summary::from_json_file(Path::new(&args.current))?;
+    compare_summaries(&current, &previous, &args.options)
+}
+
+fn print_text(analysis_result: &AnalysisResult) {
+    println!("Benchmark suite: {}", analysis_result.name());
+    println!("Metric: {}", analysis_result.metric());
+    let mut names: Vec<&String> = analysis_result
+        .equal_series()
+        .keys()
+        .chain(analysis_result.divergent_series().keys())
+        .collect();
+    names.sort();
+    for name in names {
+        let comparisons = analysis_result
+            .divergent_series()
+            .get(name)
+            .or_else(|| analysis_result.equal_series().get(name))
+            .expect("series is either equal or divergent");
+        let mut comparisons: Vec<&BenchmarkComparison> = comparisons.values().collect();
+        comparisons.sort_by_key(|comparison| comparison.point());
+        match analysis_result.classification(name) {
+            Some(classification) => println!("{}: {}", name, classification),
+            None => println!("{}:", name),
+        }
+        for comparison in comparisons {
+            let (point, previous, current, change) = match comparison {
+                BenchmarkComparison::Less {
+                    point,
+                    previous,
+                    current,
+                    change,
+                    ..
+                }
+                | BenchmarkComparison::Equal {
+                    point,
+                    previous,
+                    current,
+                    change,
+                    ..
+                }
+                | BenchmarkComparison::Greater {
+                    point,
+                    previous,
+                    current,
+                    change,
+                    ..
+                } => (point, previous, current, change),
+            };
+            let status = if comparison.is_regression() {
+                "regressed"
+            } else if comparison.is_improvement() {
+                "improved"
+            } else {
+                "equal"
+            };
+            let unit = comparison.metric().unit();
+            let precision = if comparison.metric().higher_is_better() {
+                2
+            } else {
+                0
+            };
+            let values = format!(
+                "{:.*} {} -> {:.*} {}",
+                precision, previous, unit, precision, current, unit
+            );
+            match comparison.p_value() {
+                Some(p_value) => println!(
+                    "  {}: {} ({:+.2}%, p={:.4}) {}",
+                    point, values, change, p_value, status
+                ),
+                None => println!("  {}: {} ({:+.2}%) {}", point, values, change, status),
+            }
+        }
+    }
+    println!(
+        "Regressions: {}, improvements: {}",
+        analysis_result.regression_count(),
+        analysis_result.improvement_count()
+    );
+    for (name, comparison) in analysis_result.worst_regressions(1) {
+        println!(
+            "Worst regression: {} {}: {:+.2}%",
+            name,
+            comparison.point(),
+            comparison.change()
+        );
+    }
+    let mut new_series: Vec<&String> = analysis_result.new_series().iter().collect();
+    new_series.sort();
+    for name in new_series {
+        println!("{}: new", name);
+    }
+    let mut removed_series: Vec<&String> = analysis_result.removed_series().iter().collect();
+    removed_series.sort();
+    for name in removed_series {
+        println!("{}: removed", name);
+    }
+    let mut new_points: Vec<_> = analysis_result.new_points().iter().collect();
+    new_points.sort_by_key(|(name, _points)| *name);
+    for (name, points) in new_points {
+        for point in points {
+            println!("{} {}: new", name, point);
+        }
+    }
+    let mut removed_points: Vec<_> = analysis_result.removed_points().iter().collect();
+    removed_points.sort_by_key(|(name, _points)| *name);
+    for (name, points) in removed_points {
+        for point in points {
+            println!("{} {}: removed", name, point);
+        }
+    }
+    let mut incomparable_points: Vec<_> = analysis_result.incomparable_points().iter().collect();
+    incomparable_points.sort_by_key(|(name, _points)| *name);
+    for (name, points) in incomparable_points {
+        for point in points {
+            println!("{} {}: not comparable", name, point);
+        }
+    }
+    let mut failed_series: Vec<_> = analysis_result.failed_series().iter().collect();
+    failed_series.sort_by_key(|(name, _failures)| *name);
+    for (name, failures) in failed_series {
+        for failure in failures {
+            match failure.point() {
+                Some(point) => println!(
+                    "{} {}: {}: {}",
+                    name,
+                    point,
+                    failure.kind(),
+                    failure.message()
+                ),
+                None => println!("{}: {}: {}", name, failure.kind(), failure.message()),
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|command| command.as_str()) {
+        Some("-h") | Some("--help") => {
+            println!("{}", usage());
+            ExitCode::SUCCESS
+        }
+        Some("compare") => {
+            if args[1..].iter().any(|arg| arg == "-h" || arg == "--help") {
+                println!("{}", usage());
+                return ExitCode::SUCCESS;
+            }
+            let compare_args = match CompareArgs::parse(&args[1..]) {
+                Ok(compare_args) => compare_args,
+                Err(e) => {
+                    eprintln!("{:#}\n\n{}", e, usage());
+                    return ExitCode::from(2);
+                }
+            };
+            match compare(&compare_args) {
+                Ok(analysis_result) => {
+                    match compare_args.format {
+                        Format::Text => print_text(&analysis_result),
+                        Format::Json => println!("{}", analysis_result),
+                    }
+                    compare_args.comparison.exit_code(&analysis_result)
+                }
+                Err(e) => {
+                    eprintln!("Error: {:#}", e);
+                    ExitCode::from(2)
+                }
+            }
+        }
+        _ => {
+            eprintln!("{}", usage());
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the bootstrap confidence intervals of the median and the mean of each
+/// workload point
+///
+/// The intervals are computed with the percentile method from `resamples` resamples of the
+/// measured samples. Resampling uses a pseudo random generator seeded with `seed`, so the same
+/// samples always produce the same intervals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bootstrap {
+    confidence: f64,
+    resamples: usize,
+    seed: u64,
+}
+
+impl Default for Bootstrap {
+    fn default() -> Self {
+        Bootstrap::new(95.0, 1000, 0)
+    }
+}
+
+impl Bootstrap {
+    /// Create a [Bootstrap] configuration
+    ///
+    /// * `confidence` - confidence level in percents, between 0 and 100 exclusive
+    /// * `resamples` - number of resamples
+    /// * `seed` - seed of the pseudo random generator
+    pub fn new(confidence: f64, resamples: usize, seed: u64) -> Bootstrap {
+        Bootstrap {
+            confidence,
+            resamples,
+            seed,
+        }
+    }
+
+    /// Get the confidence level in percents
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+
+    /// Get the number of resamples
+    pub fn resamples(&self) -> usize {
+        self.resamples
+    }
+
+    /// Get the seed of the pseudo random generator
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+/// Confidence interval of a statistic of the samples of a workload point
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceInterval {
+    confidence: f64,
+    lower_nanos: f64,
+    upper_nanos: f64,
+}
+
+impl ConfidenceInterval {
+    pub(crate) fn new(confidence: f64, lower_nanos: f64, upper_nanos: f64) -> ConfidenceInterval {
+        ConfidenceInterval {
+            confidence,
+            lower_nanos,
+            upper_nanos,
+        }
+    }
+
+    /// Confidence level in percents
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+
+    /// Lower bound in nanoseconds
+    pub fn lower_nanos(&self) -> f64 {
+        self.lower_nanos
+    }
+
+    /// Upper bound in nanoseconds
+    pub fn upper_nanos(&self) -> f64 {
+        self.upper_nanos
+    }
+
+    /// Check if the intervals have any duration in common
+    pub fn overlaps(&self, other: &ConfidenceInterval) -> bool {
+        self.lower_nanos <= other.upper_nanos && other.lower_nanos <= self.upper_nanos
+    }
+}
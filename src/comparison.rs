@@ -0,0 +1,505 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Error};
+
+use crate::analysis_result::AnalysisResult;
+use crate::benchmark_comparison::BenchmarkComparison;
+use crate::metric::Metric;
+use crate::run_summary::RunSummary;
+use crate::series_summary::SeriesSummary;
+use crate::statistics;
+use crate::summary::Summary;
+use crate::threshold::{Threshold, ThresholdPolicy};
+
+/// Statistical test of the difference between the samples of a workload point in two runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignificanceTest {
+    /// Mann-Whitney U test, makes no assumption about the distribution of the samples
+    MannWhitney,
+    /// Welch's t-test of the difference between the means
+    Welch,
+}
+
+impl SignificanceTest {
+    /// Name of the test on the command line, `mann-whitney` or `welch`
+    pub fn name(&self) -> &'static str {
+        match self {
+            SignificanceTest::MannWhitney => "mann-whitney",
+            SignificanceTest::Welch => "welch",
+        }
+    }
+
+    /// Parse the name of the test, see [Self::name]
+    pub fn from_name(name: &str) -> Option<SignificanceTest> {
+        match name {
+            "mann-whitney" => Some(SignificanceTest::MannWhitney),
+            "welch" => Some(SignificanceTest::Welch),
+            _ => None,
+        }
+    }
+}
+
+/// Options of the comparison of benchmark results
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonOptions {
+    threshold: f64,
+    threshold_policy: ThresholdPolicy,
+    metric: Metric,
+    confidence_intervals: bool,
+    significance_test: Option<SignificanceTest>,
+    significance_level: f64,
+}
+
+impl Default for ComparisonOptions {
+    fn default() -> Self {
+        ComparisonOptions::new(5.0)
+    }
+}
+
+impl ComparisonOptions {
+    /// Create [ComparisonOptions]
+    ///
+    /// * `threshold` - change in percents within which results are considered equal
+    pub fn new(threshold: f64) -> ComparisonOptions {
+        ComparisonOptions {
+            threshold,
+            threshold_policy: ThresholdPolicy::new(),
+            metric: Metric::Median,
+            confidence_intervals: false,
+            significance_test: None,
+            significance_level: 0.05,
+        }
+    }
+
+    /// Get the threshold in percents
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Set the threshold in percents
+    pub fn set_threshold(&mut self, threshold: f64) {
+        self.threshold = threshold;
+    }
+
+    /// Get the thresholds of individual series and workload points
+    pub fn threshold_policy(&self) -> &ThresholdPolicy {
+        &self.threshold_policy
+    }
+
+    /// Set the thresholds of individual series and workload points. Workload points without a
+    /// rule in `threshold_policy` are compared with [Self::threshold].
+    pub fn set_threshold_policy(&mut self, threshold_policy: ThresholdPolicy) {
+        self.threshold_policy = threshold_policy;
+    }
+
+    /// Get the threshold of the workload point `point` of the series `series`
+    pub fn threshold_of(&self, series: &str, point: &str) -> Threshold {
+        self.threshold_policy
+            .threshold(series, point)
+            .copied()
+            .unwrap_or(Threshold::percent(self.threshold))
+    }
+
+    /// Get the compared metric
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// Set the compared metric, [Metric::Median] by default
+    ///
+    /// Returns an error for a [Metric::Percentile] out of the range between 0 and 100.
+    pub fn set_metric(&mut self, metric: Metric) -> Result<(), Error> {
+        match metric {
+            Metric::Percentile(percentile) if !(0.0..=100.0).contains(&percentile) => {
+                Err(anyhow!("Percentile out of range: {}", percentile))
+            }
+            metric => {
+                self.metric = metric;
+                Ok(())
+            }
+        }
+    }
+
+    /// Check if overlapping confidence intervals of the median are considered equal
+    pub fn confidence_intervals(&self) -> bool {
+        self.confidence_intervals
+    }
+
+    /// Consider a workload point unchanged when the bootstrap confidence intervals of the
+    /// compared [Metric::Median] or [Metric::Mean] of both runs overlap, regardless of the
+    /// threshold. Disabled by default, has no effect when either run has no intervals, see
+    /// [crate::benchmarks::Benchmarks::set_bootstrap].
+    pub fn set_confidence_intervals(&mut self, confidence_intervals: bool) {
+        self.confidence_intervals = confidence_intervals;
+    }
+
+    /// Get the significance test
+    pub fn significance_test(&self) -> Option<SignificanceTest> {
+        self.significance_test
+    }
+
+    /// Get the significance level
+    pub fn significance_level(&self) -> f64 {
+        self.significance_level
+    }
+
+    /// Test the samples of each workload point for a significant difference
+    ///
+    /// A workload point is considered changed only when the change of the metric exceeds the
+    /// threshold and the p-value of `significance_test` is below `significance_level`. The test
+    /// runs only when both runs retained their samples, see
+    /// [crate::benchmarks::Benchmarks::set_retain_samples], otherwise the threshold alone
+    /// decides.
+    ///
+    /// * `significance_test` - the test, or `None` to compare by the threshold only
+    /// * `significance_level` - p-value below which a difference is significant, between 0 and 1
+    ///   exclusive
+    pub fn set_significance_test(
+        &mut self,
+        significance_test: Option<SignificanceTest>,
+        significance_level: f64,
+    ) -> Result<(), Error> {
+        if !(significance_level > 0.0 && significance_level < 1.0) {
+            Err(anyhow!(
+                "Significance level out of range: {}",
+                significance_level
+            ))
+        } else {
+            self.significance_test = significance_test;
+            self.significance_level = significance_level;
+            Ok(())
+        }
+    }
+}
+
+/// Compare the summary of the current run against the summary of a previous run
+///
+/// Workload points that failed in either run are not compared, failures of the current run are
+/// reported in [AnalysisResult::failed_series]. Workload points that cannot be compared by the
+/// metric, see [compare_runs], are reported in [AnalysisResult::incomparable_points]. Series and workload points that are missing in
+/// one of the runs are reported in [AnalysisResult::new_series],
+/// [AnalysisResult::removed_series], [AnalysisResult::new_points] and
+/// [AnalysisResult::removed_points], unless the series setup or teardown failed in either run.
+/// Summaries of differently named suites, or of suites run with different numbers of workers,
+/// cannot be compared.
+///
+/// ```
+/// use benchmark_rs::benchmarks::Benchmarks;
+/// use benchmark_rs::comparison::{compare_summaries, ComparisonOptions};
+/// use benchmark_rs::stopwatch::StopWatch;
+///
+/// fn sum(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+///     let _sum: u64 = (0..work).sum();
+///     Ok(())
+/// }
+///
+/// let mut benchmarks = Benchmarks::new("sums");
+/// benchmarks.add("sum", sum, "no configuration", vec![100, 1000], 10, 1).unwrap();
+/// benchmarks.run().unwrap();
+/// let previous = benchmarks.summary();
+/// benchmarks.run().unwrap();
+/// let current = benchmarks.summary();
+///
+/// let analysis_result =
+///     compare_summaries(&current, &previous, &ComparisonOptions::new(1000.0)).unwrap();
+/// assert_eq!(analysis_result.equal_series().len(), 1);
+/// ```
+pub fn compare_summaries(
+    current: &Summary,
+    previous: &Summary,
+    options: &ComparisonOptions,
+) -> Result<AnalysisResult, Error> {
+    if current.name() != previous.name() {
+        Err(anyhow!(
+            "Comparing differently named benchmarks.rs: {} <=> {}",
+            current.name(),
+            previous.name()
+        ))
+    } else if current.workers() != previous.workers() {
+        Err(anyhow!(
+            "Comparing benchmarks run with different numbers of workers: {} <=> {}",
+            current.workers(),
+            previous.workers()
+        ))
+    } else {
+        let mut analysis_result = AnalysisResult::new(current.name().clone(), options.metric);
+        for (name, current_series_summary) in current.series() {
+            if current_series_summary.has_failures() {
+                analysis_result
+                    .add_failures(name.clone(), current_series_summary.failures().clone());
+            }
+            match previous.series().get(name) {
+                None => {
+                    analysis_result.add_new(name.clone());
+                }
+                Some(prev_series_summary) => {
+                    // a series whose setup or teardown failed has no points to compare
+                    if !series_failed(current_series_summary) && !series_failed(prev_series_summary)
+                    {
+                        let (new_points, removed_points) =
+                            compare_points(current_series_summary, prev_series_summary);
+                        analysis_result.add_points(name.clone(), new_points, removed_points);
+                    }
+                    let (comparisons, incomparable_points) = compare_series_points(
+                        current_series_summary,
+                        prev_series_summary,
+                        options,
+                    )?;
+                    analysis_result.add_incomparable(name.clone(), incomparable_points);
+                    if !comparisons.is_empty() {
+                        analysis_result.add(name.clone(), comparisons);
+                    }
+                }
+            }
+        }
+        for name in previous.series().keys() {
+            if !current.series().contains_key(name) && !current.skipped().contains(name) {
+                analysis_result.add_removed(name.clone());
+            }
+        }
+        Ok(analysis_result)
+    }
+}
+
+/// Compare a series of the current run against the same series of a previous run
+///
+/// Returns the comparison of each workload point that succeeded in both runs, keyed by the
+/// workload point. Returns no comparisons when the series setup or teardown failed in either run.
+/// Workload points missing in either run are not compared, see [compare_points], nor are
+/// workload points that cannot be compared by the metric, see [compare_runs].
+pub fn compare_series(
+    current_series: &SeriesSummary,
+    previous_series: &SeriesSummary,
+    options: &ComparisonOptions,
+) -> Result<HashMap<String, BenchmarkComparison>, Error> {
+    let (comparisons, _incomparable_points) =
+        compare_series_points(current_series, previous_series, options)?;
+    Ok(comparisons)
+}
+
+/// Same as [compare_series], also returns the workload points that cannot be compared by the
+/// metric in the order of the current run
+fn compare_series_points(
+    current_series: &SeriesSummary,
+    previous_series: &SeriesSummary,
+    options: &ComparisonOptions,
+) -> Result<(HashMap<String, BenchmarkComparison>, Vec<String>), Error> {
+    let current_points = current_series.points();
+    let previous_points = previous_series.points();
+
+    if series_failed(current_series) || series_failed(previous_series) {
+        Ok((HashMap::new(), vec![]))
+    } else if current_points.is_empty() || previous_points.is_empty() {
+        Err(anyhow!("Can compare only non empty series"))
+    } else {
+        let previous_runs: HashMap<&String, &RunSummary> = previous_series
+            .runs()
+            .iter()
+            .map(|(point, run_summary)| (point, run_summary))
+            .collect();
+        let mut comparisons = HashMap::new();
+        let mut incomparable_points = vec![];
+        // points that failed in either run are reported as failures and not compared
+        for (point, current_run) in current_series.runs() {
+            if let Some(previous_run) = previous_runs.get(point) {
+                match compare_metric(point, current_run, previous_run, options) {
+                    Some(comparison) => {
+                        comparisons.insert(point.clone(), comparison);
+                    }
+                    None => incomparable_points.push(point.clone()),
+                }
+            }
+        }
+        Ok((comparisons, incomparable_points))
+    }
+}
+
+/// Check if the series setup or teardown failed
+fn series_failed(series: &SeriesSummary) -> bool {
+    series
+        .failures()
+        .iter()
+        .any(|failure| failure.point().is_none())
+}
+
+/// Find the workload points that were added to or removed from a series since a previous run
+///
+/// Returns the names of the points of the current run that are missing in the previous run, and
+/// of the points of the previous run that are missing in the current run, both in the order of
+/// the runs. Failed workload points count as present.
+pub fn compare_points(
+    current_series: &SeriesSummary,
+    previous_series: &SeriesSummary,
+) -> (Vec<String>, Vec<String>) {
+    let ordered_points = |series: &SeriesSummary| {
+        let mut points: Vec<String> = vec![];
+        let failed_points = series.failures().iter().filter_map(|f| f.point());
+        for point in series
+            .runs()
+            .iter()
+            .map(|(point, _)| point)
+            .chain(failed_points)
+        {
+            if !points.contains(point) {
+                points.push(point.clone());
+            }
+        }
+        points
+    };
+    let (current_points, previous_points) = (
+        ordered_points(current_series),
+        ordered_points(previous_series),
+    );
+    let missing = |points: &[String], other: &[String]| -> Vec<String> {
+        points
+            .iter()
+            .filter(|p| !other.contains(p))
+            .cloned()
+            .collect()
+    };
+    (
+        missing(&current_points, &previous_points),
+        missing(&previous_points, &current_points),
+    )
+}
+
+/// Compare a metric of a workload point of the current run against a previous run
+///
+/// The metric is selected by [ComparisonOptions::set_metric] and computed from the samples when
+/// both runs retained them, see [crate::benchmarks::Benchmarks::set_retain_samples]. Samples
+/// excluded from the statistics by the [crate::outliers::OutlierPolicy] of a run are excluded
+/// from the metric and from the [SignificanceTest] as well. The
+/// threshold is looked up by the series name of `current_run`, see
+/// [ComparisonOptions::threshold_of]. When both runs have overlapping confidence intervals of
+/// the compared median or mean, the runs are considered equal, see
+/// [ComparisonOptions::set_confidence_intervals]. With a [SignificanceTest] the runs are
+/// considered equal unless the difference of their samples is significant, see
+/// [ComparisonOptions::set_significance_test].
+///
+/// Returns an error when the runs cannot be compared by the metric: when a run has no value of
+/// the metric, like a percentile that was not computed, see
+/// [crate::benchmarks::Benchmarks::set_percentiles], or the mean of a summary saved before the
+/// mean was recorded, when either value is not finite, and when the value in the previous run is
+/// zero while the current one is not.
+pub fn compare_runs(
+    point: &str,
+    current_run: &RunSummary,
+    previous_run: &RunSummary,
+    options: &ComparisonOptions,
+) -> Result<BenchmarkComparison, Error> {
+    compare_metric(point, current_run, previous_run, options).ok_or_else(|| {
+        anyhow!(
+            "Workload point {} is not comparable by {}",
+            point,
+            options.metric
+        )
+    })
+}
+
+/// Same as [compare_runs], returns `None` when the runs cannot be compared by the metric
+fn compare_metric(
+    point: &str,
+    current_run: &RunSummary,
+    previous_run: &RunSummary,
+    options: &ComparisonOptions,
+) -> Option<BenchmarkComparison> {
+    let metric = options.metric;
+    let from_samples =
+        !current_run.raw_samples().is_empty() && !previous_run.raw_samples().is_empty();
+    let (current, previous) = match (
+        metric.value(current_run, from_samples),
+        metric.value(previous_run, from_samples),
+    ) {
+        (Some(current), Some(previous))
+            if current.is_finite()
+                && previous.is_finite()
+                && (previous != 0.0 || current == 0.0) =>
+        {
+            (current, previous)
+        }
+        _ => return None,
+    };
+    let intervals = match metric {
+        Metric::Median => current_run.median_ci().zip(previous_run.median_ci()),
+        Metric::Mean => current_run.mean_ci().zip(previous_run.mean_ci()),
+        _ => None,
+    };
+    let overlapping = intervals
+        .map(|(current_ci, previous_ci)| {
+            options.confidence_intervals && current_ci.overlaps(previous_ci)
+        })
+        .unwrap_or(false);
+    let significance = options.significance_test.and_then(|significance_test| {
+        let (current_samples, previous_samples) = (
+            current_run.included_samples(),
+            previous_run.included_samples(),
+        );
+        match significance_test {
+            SignificanceTest::MannWhitney => {
+                statistics::mann_whitney_u_test(&current_samples, &previous_samples)
+            }
+            SignificanceTest::Welch => {
+                statistics::welch_t_test(&current_samples, &previous_samples)
+            }
+        }
+    });
+    let insignificant = significance
+        .map(|(p_value, _effect_size)| p_value >= options.significance_level)
+        .unwrap_or(false);
+
+    let change = if current == previous {
+        0.0
+    } else {
+        (current / (previous / 100.0)) - 100.0
+    };
+    let difference_nanos = match metric {
+        Metric::Throughput => (1e9 / current - 1e9 / previous).abs(),
+        _ => (current - previous).abs(),
+    };
+    let regression = if metric.higher_is_better() {
+        -change
+    } else {
+        change
+    };
+    let threshold = options.threshold_of(current_run.name(), point);
+    let equal = current == previous
+        || threshold.is_within(difference_nanos, regression)
+        || overlapping
+        || insignificant;
+    let (p_value, effect_size) = match significance {
+        Some((p_value, effect_size)) => (Some(p_value), Some(effect_size)),
+        None => (None, None),
+    };
+    let point = point.to_owned();
+    Some(if equal {
+        BenchmarkComparison::Equal {
+            point,
+            previous,
+            current,
+            change,
+            metric,
+            p_value,
+            effect_size,
+        }
+    } else if change < 0.0 {
+        BenchmarkComparison::Less {
+            point,
+            previous,
+            current,
+            change,
+            metric,
+            p_value,
+            effect_size,
+        }
+    } else {
+        BenchmarkComparison::Greater {
+            point,
+            previous,
+            current,
+            change,
+            metric,
+            p_value,
+            effect_size,
+        }
+    })
+}
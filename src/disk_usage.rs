@@ -1,14 +1,13 @@
-use std::io::ErrorKind;
 use std::path::PathBuf;
 
 /// Measure disk usage for a path
 #[allow(dead_code)]
 pub fn disk_usage(path: &PathBuf) -> std::io::Result<u64> {
     if !path.exists() {
-        Err(std::io::Error::new(
-            ErrorKind::Other,
-            format!("path does not exist: [{}]", path.to_string_lossy()),
-        ))
+        Err(std::io::Error::other(format!(
+            "path does not exist: [{}]",
+            path.to_string_lossy()
+        )))
     } else {
         let mut size: u64 = 0;
         if path.is_dir() {
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Kind of a benchmark failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureKind {
+    /// The benchmark function, setup or teardown returned an error
+    Error,
+    /// The benchmark function, setup or teardown panicked
+    Panic,
+    /// The iteration or the workload point exceeded its timeout
+    Timeout,
+    /// The child process running the benchmark exited without reporting the outcome, see
+    /// [crate::isolation::Isolation]
+    Crash,
+}
+
+impl FailureKind {
+    /// Status of a failed workload point as it appears in CSV output
+    pub fn status(&self) -> &'static str {
+        match self {
+            FailureKind::Error => "error",
+            FailureKind::Panic => "panic",
+            FailureKind::Timeout => "timeout",
+            FailureKind::Crash => "crash",
+        }
+    }
+
+    /// Parse the status of a failed workload point as it appears in CSV output
+    pub fn from_status(status: &str) -> Option<FailureKind> {
+        match status {
+            "error" => Some(FailureKind::Error),
+            "panic" => Some(FailureKind::Panic),
+            "timeout" => Some(FailureKind::Timeout),
+            "crash" => Some(FailureKind::Crash),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FailureKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.status())
+    }
+}
+
+/// Failure of a benchmark series or of a single workload point
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Failure {
+    point: Option<String>,
+    iteration: usize,
+    kind: FailureKind,
+    message: String,
+}
+
+impl Failure {
+    pub(crate) fn new(
+        point: Option<String>,
+        iteration: usize,
+        kind: FailureKind,
+        message: String,
+    ) -> Failure {
+        Failure {
+            point,
+            iteration,
+            kind,
+            message,
+        }
+    }
+
+    /// Workload point that failed, `None` if the series setup or teardown failed
+    pub fn point(&self) -> Option<&String> {
+        self.point.as_ref()
+    }
+
+    /// Index of the failed iteration of the workload point, ramp up and batch calibration
+    /// iterations included
+    pub fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    /// Kind of the failure
+    pub fn kind(&self) -> FailureKind {
+        self.kind
+    }
+
+    /// Failure description
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::Error;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Pattern matched against benchmark series names
+///
+/// The pattern is compiled to a regular expression once, when it is created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "PatternSource", into = "PatternSource")]
+pub struct NamePattern {
+    source: PatternSource,
+    regex: Regex,
+}
+
+/// Kind and text of a [NamePattern], as it is serialized
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum PatternSource {
+    Substring(String),
+    Glob(String),
+    Regex(String),
+}
+
+impl NamePattern {
+    /// Create a pattern that matches names that contain `substring`
+    pub fn substring(substring: &str) -> NamePattern {
+        NamePattern::compile(PatternSource::Substring(substring.to_string()))
+            .expect("escaped substring is a valid regular expression")
+    }
+
+    /// Create a pattern that matches the whole name against `glob`, where `*` matches any
+    /// sequence of characters and `?` matches a single character
+    pub fn glob(glob: &str) -> NamePattern {
+        NamePattern::compile(PatternSource::Glob(glob.to_string()))
+            .expect("escaped glob is a valid regular expression")
+    }
+
+    /// Create a pattern that matches names against the regular expression `regex`, see
+    /// [regex::Regex] for the syntax
+    ///
+    /// Returns an error if `regex` is not a valid regular expression.
+    pub fn regex(regex: &str) -> Result<NamePattern, Error> {
+        NamePattern::compile(PatternSource::Regex(regex.to_string()))
+    }
+
+    fn compile(source: PatternSource) -> Result<NamePattern, Error> {
+        let regex = match &source {
+            PatternSource::Substring(substring) => regex::escape(substring),
+            PatternSource::Glob(glob) => {
+                let mut regex = String::from("^");
+                for c in glob.chars() {
+                    match c {
+                        '*' => regex.push_str(".*"),
+                        '?' => regex.push('.'),
+                        c => regex.push_str(&regex::escape(&c.to_string())),
+                    }
+                }
+                regex.push('$');
+                regex
+            }
+            PatternSource::Regex(regex) => regex.clone(),
+        };
+        Ok(NamePattern {
+            regex: Regex::new(&regex)?,
+            source,
+        })
+    }
+
+    /// Get the pattern as it was created
+    pub fn as_str(&self) -> &str {
+        match &self.source {
+            PatternSource::Substring(pattern)
+            | PatternSource::Glob(pattern)
+            | PatternSource::Regex(pattern) => pattern,
+        }
+    }
+
+    /// Check if the pattern matches `name`
+    pub fn matches(&self, name: &str) -> bool {
+        self.regex.is_match(name)
+    }
+}
+
+impl PartialEq for NamePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for NamePattern {}
+
+impl TryFrom<PatternSource> for NamePattern {
+    type Error = Error;
+
+    fn try_from(source: PatternSource) -> Result<Self, Self::Error> {
+        NamePattern::compile(source)
+    }
+}
+
+impl From<NamePattern> for PatternSource {
+    fn from(pattern: NamePattern) -> Self {
+        pattern.source
+    }
+}
+
+/// Selection of the benchmark series to run
+///
+/// A series is selected when its name matches any of the name patterns and it is tagged with any
+/// of the tags. An empty list of name patterns or of tags selects all series.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Filter {
+    names: Vec<NamePattern>,
+    tags: Vec<String>,
+}
+
+impl Filter {
+    /// Create a [Filter] that selects all series
+    pub fn new() -> Filter {
+        Filter::default()
+    }
+
+    /// Select series with names that match `pattern`
+    pub fn add_name(&mut self, pattern: NamePattern) {
+        self.names.push(pattern);
+    }
+
+    /// Select series tagged with `tag`, see [crate::benchmarks::Benchmarks::set_tags]
+    pub fn add_tag(&mut self, tag: &str) {
+        self.tags.push(tag.to_string());
+    }
+
+    /// Get the name patterns
+    pub fn names(&self) -> &Vec<NamePattern> {
+        &self.names
+    }
+
+    /// Get the tags
+    pub fn tags(&self) -> &Vec<String> {
+        &self.tags
+    }
+
+    /// Check if the series `name` tagged with `tags` is selected
+    pub fn matches(&self, name: &str, tags: &[String]) -> bool {
+        (self.names.is_empty() || self.names.iter().any(|pattern| pattern.matches(name)))
+            && (self.tags.is_empty() || self.tags.iter().any(|tag| tags.contains(tag)))
+    }
+}
@@ -0,0 +1,497 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::{anyhow, Context, Error};
+
+use crate::analysis_result::AnalysisResult;
+use crate::benchmarks::Benchmarks;
+use crate::comparison::{ComparisonOptions, SignificanceTest};
+use crate::filter::{Filter, NamePattern};
+use crate::metric::Metric;
+use crate::threading::Dispatch;
+
+const USAGE: &str = "\
+Usage: <bench> [OPTIONS] [FILTER]...
+
+Arguments:
+  [FILTER]...                 Run only series with names containing FILTER
+
+Options:
+  --filter <PATTERN>          Run only series with names matching PATTERN, a substring,
+                              glob:<GLOB> or regex:<REGEX>. May be repeated
+  --tag <TAG>                 Run only series tagged with TAG. May be repeated
+  --list                      List the selected series and exit
+  --save-baseline <NAME>      Save the summary as the baseline NAME
+  --baseline <NAME>           Compare the summary with the baseline NAME
+  --output-dir <DIR>          Directory for results and baselines [default: target/benchmarks]
+  --format <FORMAT>           Result format, json or csv. May be repeated [default: json]";
+
+/// Flags passed by `cargo bench` and `cargo test` that are accepted and ignored
+const IGNORED_FLAGS: [&str; 7] = [
+    "--bench",
+    "--test",
+    "--nocapture",
+    "--show-output",
+    "--exact",
+    "--quiet",
+    "-q",
+];
+
+/// Flags passed by `cargo bench` and `cargo test` that take a value and are accepted and ignored
+const IGNORED_OPTIONS: [&str; 2] = ["--color", "--test-threads"];
+
+/// Format of the saved results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The [crate::summary::Summary] as a single JSON file, see [Benchmarks::save_to_json]
+    Json,
+    /// A CSV file for each series, see [Benchmarks::save_to_csv]
+    Csv,
+}
+
+/// Parse command line arguments, not including the program name
+///
+/// Each option is passed to `option` with a function that takes its value, either after `=`, as
+/// in `--threshold=10`, or as the next argument. `option` returns false for an option it does not
+/// know. Returns the positional arguments.
+pub fn parse_args<I, F>(args: I, mut option: F) -> Result<Vec<String>, Error>
+where
+    I: IntoIterator<Item = String>,
+    F: FnMut(&str, &mut dyn FnMut() -> Result<String, Error>) -> Result<bool, Error>,
+{
+    let mut positional = vec![];
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') {
+            positional.push(arg);
+            continue;
+        }
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                (flag.to_string(), Some(value.to_string()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| anyhow!("Missing value for {}", flag))
+        };
+        if !option(&flag, &mut value)? {
+            return Err(anyhow!("Unknown option: {}", flag));
+        }
+    }
+    Ok(positional)
+}
+
+/// Command line options of the comparison with a previous run, shared by benchmark binaries and
+/// the `benchmark-rs compare` command
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonArgs {
+    threshold: f64,
+    metric: Option<Metric>,
+    significance: Option<SignificanceTest>,
+    significance_level: f64,
+    fail_on_removed: bool,
+}
+
+impl Default for ComparisonArgs {
+    fn default() -> Self {
+        ComparisonArgs {
+            threshold: 5.0,
+            metric: None,
+            significance: None,
+            significance_level: 0.05,
+            fail_on_removed: false,
+        }
+    }
+}
+
+impl ComparisonArgs {
+    /// Usage of the comparison options
+    pub const USAGE: &'static str =
+        "  --threshold <PERCENT>       Change in percents considered a regression [default: 5]
+  --metric <METRIC>           Compared statistic, min, median, mean, p<PERCENTILE> or
+                              throughput [default: median]
+  --significance <TEST>       Consider only significant changes, tested with mann-whitney or
+                              welch on the samples retained by both runs
+  --significance-level <P>    p-value below which a change is significant [default: 0.05]
+  --fail-on-removed           Fail when series or workload points of the previous run were removed";
+
+    /// Parse the comparison option `flag`, see [parse_args]
+    ///
+    /// Returns false when `flag` is not a comparison option.
+    pub fn parse_option(
+        &mut self,
+        flag: &str,
+        value: &mut dyn FnMut() -> Result<String, Error>,
+    ) -> Result<bool, Error> {
+        match flag {
+            "--threshold" => {
+                let threshold = value()?;
+                self.threshold = threshold
+                    .parse()
+                    .with_context(|| anyhow!("Invalid threshold: {}", threshold))?;
+            }
+            "--metric" => self.metric = Some(Metric::from_name(&value()?)?),
+            "--significance" => {
+                let significance = value()?;
+                self.significance = Some(
+                    SignificanceTest::from_name(&significance)
+                        .ok_or_else(|| anyhow!("Unknown significance test: {}", significance))?,
+                );
+            }
+            "--significance-level" => {
+                let significance_level = value()?;
+                self.significance_level = significance_level.parse().with_context(|| {
+                    anyhow!("Invalid significance level: {}", significance_level)
+                })?;
+            }
+            "--fail-on-removed" => self.fail_on_removed = true,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Get the regression threshold in percents
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Get the compared metric, if given
+    pub fn metric(&self) -> Option<Metric> {
+        self.metric
+    }
+
+    /// Get the significance test
+    pub fn significance(&self) -> Option<SignificanceTest> {
+        self.significance
+    }
+
+    /// Get the significance level
+    pub fn significance_level(&self) -> f64 {
+        self.significance_level
+    }
+
+    /// Check if removed series and workload points should fail the run
+    pub fn fail_on_removed(&self) -> bool {
+        self.fail_on_removed
+    }
+
+    /// Build the [ComparisonOptions] selected by the options
+    pub fn comparison_options(&self) -> Result<ComparisonOptions, Error> {
+        let mut options = ComparisonOptions::new(self.threshold);
+        if let Some(metric) = self.metric {
+            options.set_metric(metric)?;
+        }
+        options.set_significance_test(self.significance, self.significance_level)?;
+        Ok(options)
+    }
+
+    /// Exit code for the comparison result: 1 when a regression was found, when the current run
+    /// recorded failures, when workload points could not be compared by the metric, or with
+    /// `--fail-on-removed` when series or workload points of the previous run were removed, and
+    /// 0 otherwise
+    pub fn exit_code(&self, analysis_result: &AnalysisResult) -> ExitCode {
+        if analysis_result.has_regressions()
+            || analysis_result.has_failures()
+            || analysis_result.has_incomparable()
+            || (self.fail_on_removed && analysis_result.has_removed())
+        {
+            ExitCode::from(1)
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// Command line arguments of a benchmark binary
+#[derive(Debug, Clone, PartialEq)]
+pub struct HarnessArgs {
+    filter: Filter,
+    list: bool,
+    help: bool,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    comparison: ComparisonArgs,
+    output_dir: PathBuf,
+    formats: Vec<OutputFormat>,
+}
+
+impl Default for HarnessArgs {
+    fn default() -> Self {
+        HarnessArgs {
+            filter: Filter::new(),
+            list: false,
+            help: false,
+            save_baseline: None,
+            baseline: None,
+            comparison: ComparisonArgs::default(),
+            output_dir: PathBuf::from("target/benchmarks"),
+            formats: vec![],
+        }
+    }
+}
+
+impl HarnessArgs {
+    /// Parse the command line arguments of the current process
+    pub fn from_env() -> Result<HarnessArgs, Error> {
+        HarnessArgs::parse(std::env::args().skip(1))
+    }
+
+    /// Parse command line arguments, not including the program name, see [parse_args]
+    pub fn parse<I>(args: I) -> Result<HarnessArgs, Error>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut harness_args = HarnessArgs::default();
+        let positional = parse_args(args, |flag, value| {
+            match flag {
+                "--filter" => harness_args.filter.add_name(Self::name_pattern(value()?)?),
+                "--tag" => harness_args.filter.add_tag(&value()?),
+                "--list" => harness_args.list = true,
+                "-h" | "--help" => harness_args.help = true,
+                "--save-baseline" => harness_args.save_baseline = Some(value()?),
+                "--baseline" => harness_args.baseline = Some(value()?),
+                "--output-dir" => harness_args.output_dir = PathBuf::from(value()?),
+                "--format" => match value()?.as_str() {
+                    "json" => harness_args.formats.push(OutputFormat::Json),
+                    "csv" => harness_args.formats.push(OutputFormat::Csv),
+                    format => return Err(anyhow!("Unknown format: {}", format)),
+                },
+                flag if IGNORED_FLAGS.contains(&flag) => {}
+                flag if IGNORED_OPTIONS.contains(&flag) => {
+                    value()?;
+                }
+                flag => return harness_args.comparison.parse_option(flag, value),
+            }
+            Ok(true)
+        })?;
+        for arg in positional {
+            harness_args.filter.add_name(NamePattern::substring(&arg));
+        }
+        if harness_args.formats.is_empty() {
+            harness_args.formats.push(OutputFormat::Json);
+        }
+        Ok(harness_args)
+    }
+
+    /// Usage of the command line arguments
+    pub fn usage() -> String {
+        format!(
+            "{}\n{}\n  -h, --help                  Print help",
+            USAGE,
+            ComparisonArgs::USAGE
+        )
+    }
+
+    fn name_pattern(pattern: String) -> Result<NamePattern, Error> {
+        if let Some(glob) = pattern.strip_prefix("glob:") {
+            Ok(NamePattern::glob(glob))
+        } else if let Some(regex) = pattern.strip_prefix("regex:") {
+            NamePattern::regex(regex)
+        } else {
+            Ok(NamePattern::substring(&pattern))
+        }
+    }
+
+    /// Get the [Filter] built from `--filter`, `--tag` and the positional arguments
+    pub fn filter(&self) -> &Filter {
+        &self.filter
+    }
+
+    /// Check if the selected series should be listed instead of run
+    pub fn list(&self) -> bool {
+        self.list
+    }
+
+    /// Get the name of the baseline to save the summary as
+    pub fn save_baseline(&self) -> Option<&String> {
+        self.save_baseline.as_ref()
+    }
+
+    /// Get the name of the baseline to compare the summary with
+    pub fn baseline(&self) -> Option<&String> {
+        self.baseline.as_ref()
+    }
+
+    /// Get the options of the comparison with the baseline
+    pub fn comparison(&self) -> &ComparisonArgs {
+        &self.comparison
+    }
+
+    /// Get the regression threshold in percents
+    pub fn threshold(&self) -> f64 {
+        self.comparison.threshold()
+    }
+
+    /// Get the compared metric, if given
+    pub fn metric(&self) -> Option<Metric> {
+        self.comparison.metric()
+    }
+
+    /// Get the significance test
+    pub fn significance(&self) -> Option<SignificanceTest> {
+        self.comparison.significance()
+    }
+
+    /// Get the significance level
+    pub fn significance_level(&self) -> f64 {
+        self.comparison.significance_level()
+    }
+
+    /// Check if removed series and workload points should fail the run
+    pub fn fail_on_removed(&self) -> bool {
+        self.comparison.fail_on_removed()
+    }
+
+    /// Get the directory for results and baselines
+    pub fn output_dir(&self) -> &PathBuf {
+        &self.output_dir
+    }
+
+    /// Get the formats of the saved results
+    pub fn formats(&self) -> &Vec<OutputFormat> {
+        &self.formats
+    }
+
+    fn baseline_dir(&self, name: &str) -> PathBuf {
+        self.output_dir.join("baselines").join(name)
+    }
+}
+
+/// Run `benchmarks` as directed by `args`
+///
+/// Lists the selected series when `--list` is given. Otherwise runs the selected series, saves
+/// the results to the output directory, compares the summary with the `--baseline` and saves it
+/// as the `--save-baseline`. With `--significance` the samples are retained, so that baselines
+/// saved with it can be tested. Baselines are stored as
+/// `<output-dir>/baselines/<baseline>/<suite>.json`.
+///
+/// Returns the [AnalysisResult] when the summary was compared with a baseline.
+pub fn run<C, W, E, T>(
+    benchmarks: &mut Benchmarks<C, W, E, T>,
+    args: &HarnessArgs,
+) -> Result<Option<AnalysisResult>, Error>
+where
+    C: Clone + Display,
+    W: Clone + Display,
+    Error: From<E>,
+    T: Dispatch<C, W, E>,
+{
+    benchmarks.set_filter(args.filter.clone());
+    if let Some(metric) = args.metric() {
+        benchmarks.set_comparison_metric(metric)?;
+    }
+    if args.significance().is_some() {
+        benchmarks.set_significance_test(args.significance(), args.significance_level())?;
+        benchmarks.set_retain_samples(true);
+    }
+    if args.list {
+        for name in benchmarks.selected_names() {
+            println!("{}", name);
+        }
+        return Ok(None);
+    }
+
+    benchmarks.run()?;
+    for format in &args.formats {
+        match format {
+            OutputFormat::Json => benchmarks.save_to_json(args.output_dir.clone())?,
+            OutputFormat::Csv => benchmarks.save_to_csv(args.output_dir.clone(), true, true)?,
+        }
+    }
+
+    let analysis_result = match &args.baseline {
+        None => None,
+        Some(baseline) => {
+            let path = args
+                .baseline_dir(baseline)
+                .join(format!("{}.json", benchmarks.name()));
+            Some(benchmarks.analyze_against_file(&path, args.threshold())?)
+        }
+    };
+    if let Some(save_baseline) = &args.save_baseline {
+        benchmarks.save_to_json(args.baseline_dir(save_baseline))?;
+    }
+    Ok(analysis_result)
+}
+
+/// Entry point of a benchmark binary
+///
+/// Parses the command line, builds the suite with `build` and runs it with [run]. Prints the
+/// analysis result when the summary was compared with a baseline. Exits with code 1 when the run
+/// recorded failures or when the comparison with the baseline fails the run, see
+/// [ComparisonArgs::exit_code], and with code 2 on invalid arguments or when the suite failed.
+///
+/// See [crate::benchmark_main] to generate the `main` function of a `harness = false` target.
+pub fn main<C, W, E, T, F>(build: F) -> ExitCode
+where
+    C: Clone + Display,
+    W: Clone + Display,
+    Error: From<E>,
+    T: Dispatch<C, W, E>,
+    F: FnOnce() -> Result<Benchmarks<C, W, E, T>, Error>,
+{
+    let args = match HarnessArgs::from_env() {
+        Ok(args) if args.help => {
+            println!("{}", HarnessArgs::usage());
+            return ExitCode::SUCCESS;
+        }
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{:#}\n\n{}", e, HarnessArgs::usage());
+            return ExitCode::from(2);
+        }
+    };
+    let result = build().and_then(|mut benchmarks| {
+        let analysis_result = run(&mut benchmarks, &args)?;
+        Ok((analysis_result, benchmarks.summary().has_failures()))
+    });
+    match result {
+        Ok((Some(analysis_result), _failed)) => {
+            println!("{}", analysis_result);
+            args.comparison.exit_code(&analysis_result)
+        }
+        Ok((None, true)) => ExitCode::from(1),
+        Ok((None, false)) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Generate the `main` function of a `harness = false` benchmark target
+///
+/// The argument is a function or closure that builds the [Benchmarks] suite, see
+/// [crate::harness::main].
+///
+/// ```no_run
+/// use benchmark_rs::benchmark_main;
+/// use benchmark_rs::benchmarks::Benchmarks;
+/// use benchmark_rs::stopwatch::StopWatch;
+///
+/// fn sum(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+///     let _sum: u64 = (0..work).sum();
+///     Ok(())
+/// }
+///
+/// fn suite() -> Result<Benchmarks<&'static str, u64, anyhow::Error>, anyhow::Error> {
+///     let mut benchmarks = Benchmarks::new("sums");
+///     benchmarks.add("sum", sum, "no configuration", vec![100, 1000], 10, 1)?;
+///     Ok(benchmarks)
+/// }
+///
+/// benchmark_main!(suite);
+/// ```
+#[macro_export]
+macro_rules! benchmark_main {
+    ($build:expr) => {
+        fn main() -> std::process::ExitCode {
+            $crate::harness::main($build)
+        }
+    };
+}
@@ -0,0 +1,250 @@
+use std::env;
+use std::io::{BufRead, BufReader, Write};
+use std::ops::Range;
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Error};
+
+use crate::failure::{Failure, FailureKind};
+use crate::series_summary::SeriesSummary;
+
+const SUITE_VAR: &str = "BENCHMARK_RS_ISOLATED_SUITE";
+const SERIES_VAR: &str = "BENCHMARK_RS_ISOLATED_SERIES";
+const POINTS_VAR: &str = "BENCHMARK_RS_ISOLATED_POINTS";
+const FRAGMENT_MARKER: &str = "benchmark-rs:fragment:";
+const ERROR_MARKER: &str = "benchmark-rs:error:";
+
+/// Process isolation of the benchmarks in a suite
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Isolation {
+    /// Run all benchmarks in the current process
+    #[default]
+    None,
+    /// Run each benchmark series in its own child process
+    Series,
+    /// Run each workload point in its own child process
+    Point,
+}
+
+/// Benchmark series and workload points a child process was started for
+pub(crate) struct Selection {
+    pub(crate) suite: String,
+    pub(crate) series: String,
+    pub(crate) points: Range<usize>,
+}
+
+/// Benchmark series to run in child processes
+pub(crate) struct IsolatedSeries {
+    pub(crate) name: String,
+    pub(crate) series_summary: SeriesSummary,
+    pub(crate) points: Vec<String>,
+    pub(crate) point_timeout: Option<Duration>,
+}
+
+impl IsolatedSeries {
+    /// Run the benchmark series in child processes and assemble its [SeriesSummary] from the
+    /// fragments the children report
+    ///
+    /// A child that is killed on timeout or that crashes fails the workload point it was running,
+    /// and the series is resumed from the next workload point in a new child. A failed series
+    /// setup stops the series. With [Isolation::Point] a failed series teardown is recorded
+    /// against the workload point of the child and the series carries on with the next child.
+    pub(crate) fn run(self, suite: &str, isolation: Isolation) -> Result<SeriesSummary, Error> {
+        let mut series_summary = self.series_summary;
+        let mut start = 0;
+        loop {
+            let end = match isolation {
+                Isolation::Point => (start + 1).min(self.points.len()),
+                _ => self.points.len(),
+            };
+            let mut completed = 0;
+            let mut series_failed = false;
+            let outcome = run_child(
+                suite,
+                &self.name,
+                start..end,
+                self.point_timeout,
+                &mut |fragment| {
+                    if !fragment.points().is_empty() {
+                        completed += 1;
+                        series_summary.merge(fragment);
+                    } else if completed == 0 {
+                        // the series setup failed
+                        series_failed = true;
+                        series_summary.merge(fragment);
+                    } else if isolation == Isolation::Point {
+                        // the series teardown failed after the workload point of the child
+                        let point = &self.points[start + completed - 1];
+                        for failure in fragment.failures() {
+                            series_summary.add_failure(Failure::new(
+                                Some(point.clone()),
+                                failure.iteration(),
+                                failure.kind(),
+                                failure.message().clone(),
+                            ));
+                        }
+                    } else {
+                        series_summary.merge(fragment);
+                    }
+                },
+            )?;
+            let current = start + completed;
+            let point = (current < end).then(|| self.points[current].clone());
+            let failure = match outcome {
+                ChildOutcome::Completed => None,
+                ChildOutcome::Failed(message) => return Err(anyhow!(message)),
+                ChildOutcome::TimedOut => Some(Failure::new(
+                    point,
+                    0,
+                    FailureKind::Timeout,
+                    "child process killed on point timeout".to_string(),
+                )),
+                ChildOutcome::Crashed(status) => Some(Failure::new(
+                    point,
+                    0,
+                    FailureKind::Crash,
+                    format!("child process exited with {status}"),
+                )),
+            };
+            start = match failure {
+                Some(failure) => {
+                    series_summary.add_failure(failure);
+                    current + 1
+                }
+                None => end,
+            };
+            if series_failed || start >= self.points.len() {
+                return Ok(series_summary);
+            }
+        }
+    }
+}
+
+/// How a child process ended
+enum ChildOutcome {
+    /// The child ran all selected workload points
+    Completed,
+    /// The child stopped on an error
+    Failed(String),
+    /// The child was killed after it did not report a workload point within the timeout
+    TimedOut,
+    /// The child exited without completing the selected workload points
+    Crashed(ExitStatus),
+}
+
+/// Get the [Selection] when running in a child process
+pub(crate) fn selection() -> Result<Option<Selection>, Error> {
+    match (
+        env::var(SUITE_VAR),
+        env::var(SERIES_VAR),
+        env::var(POINTS_VAR),
+    ) {
+        (Ok(suite), Ok(series), Ok(points)) => {
+            let (start, end) = points
+                .split_once("..")
+                .ok_or_else(|| anyhow!("Invalid workload points selection: {}", points))?;
+            Ok(Some(Selection {
+                suite,
+                series,
+                points: start.parse()?..end.parse()?,
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Whether this is a child process started to run isolated benchmarks
+pub(crate) fn in_child() -> bool {
+    env::var_os(SUITE_VAR).is_some()
+}
+
+/// Report the outcome of a workload point, or of the series setup or teardown, from a child
+/// process to the parent
+pub(crate) fn report_fragment(fragment: SeriesSummary) {
+    let json = serde_json::to_string(&fragment).expect("failed to serialize series summary");
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(stdout, "{FRAGMENT_MARKER}{json}");
+    let _ = stdout.flush();
+}
+
+/// Report an error that stopped the run from a child process to the parent
+pub(crate) fn report_error(e: &Error) {
+    let json = serde_json::to_string(&format!("{e:#}")).expect("failed to serialize error");
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(stdout, "{ERROR_MARKER}{json}");
+    let _ = stdout.flush();
+}
+
+/// Re-execute the current binary to run the workload points `points` of the benchmark series
+/// `series`
+///
+/// Each fragment reported by the child is passed to `on_fragment`. Output of the child that is
+/// not part of the protocol is forwarded to the standard output. When `timeout` is set, the child
+/// is killed if it does not report a fragment within the timeout.
+fn run_child(
+    suite: &str,
+    series: &str,
+    points: Range<usize>,
+    timeout: Option<Duration>,
+    on_fragment: &mut dyn FnMut(SeriesSummary),
+) -> Result<ChildOutcome, Error> {
+    let mut child = Command::new(env::current_exe()?)
+        .args(env::args_os().skip(1))
+        .env(SUITE_VAR, suite)
+        .env(SERIES_VAR, series)
+        .env(POINTS_VAR, format!("{}..{}", points.start, points.end))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start child process for {}", series))?;
+
+    let stdout = child.stdout.take().expect("child stdout is piped");
+    let (sender, receiver) = channel();
+    let reader = thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut error = None;
+    let mut deadline = timeout.map(|timeout| Instant::now() + timeout);
+    loop {
+        let line = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match line {
+            Ok(line) => {
+                if let Some(json) = line.strip_prefix(FRAGMENT_MARKER) {
+                    on_fragment(serde_json::from_str(json)?);
+                    deadline = timeout.map(|timeout| Instant::now() + timeout);
+                } else if let Some(json) = line.strip_prefix(ERROR_MARKER) {
+                    error = Some(serde_json::from_str(json)?);
+                } else {
+                    println!("{line}");
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                child.kill()?;
+                child.wait()?;
+                return Ok(ChildOutcome::TimedOut);
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    let _ = reader.join();
+
+    let status = child.wait()?;
+    Ok(match error {
+        Some(message) => ChildOutcome::Failed(message),
+        None if status.success() => ChildOutcome::Completed,
+        None => ChildOutcome::Crashed(status),
+    })
+}
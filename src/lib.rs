@@ -172,6 +172,12 @@
 //! {
 //!   "name": "benchmark-workloads",
 //!   "created_at": "2023-08-13 04:09:13.923036",
+//!   "workers": 1,
+//!   "filter": {
+//!     "names": [],
+//!     "tags": []
+//!   },
+//!   "skipped": [],
 //!   "series": {
 //!     "benchmark-workload-2": {
 //!       "name": "benchmark-workload-2",
@@ -183,6 +189,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 639791,
 //!             "min_sec": 0.000639791,
 //!             "min_str": "00:00:00.000",
@@ -194,7 +201,42 @@
 //!             "median_str": "00:00:00.000",
 //!             "std_dev": 3152.9891373108153,
 //!             "std_dev_sec": 3.1529891373108154e-6,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 642020.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 640013.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 640905.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 643135.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 643804.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 644027.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 644205.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 2229.0,
+//!             "mad_nanos": 2229.0,
+//!             "cv": 0.004911041216457754,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -203,6 +245,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 1264124,
 //!             "min_sec": 0.001264124,
 //!             "min_str": "00:00:00.001",
@@ -214,7 +257,42 @@
 //!             "median_str": "00:00:00.001",
 //!             "std_dev": 89.09545442950498,
 //!             "std_dev_sec": 8.909545442950498e-8,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 1264187.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 1264130.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 1264155.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 1264218.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 1264237.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 1264243.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 1264248.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 63.0,
+//!             "mad_nanos": 63.0,
+//!             "cv": 7.047648364482863e-05,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -223,6 +301,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 1891833,
 //!             "min_sec": 0.001891833,
 //!             "min_str": "00:00:00.001",
@@ -234,7 +313,42 @@
 //!             "median_str": "00:00:00.001",
 //!             "std_dev": 736.8052659963826,
 //!             "std_dev_sec": 7.368052659963826e-7,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 1892354.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 1891885.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 1892093.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 1892614.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 1892770.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 1892822.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 1892864.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 521.0,
+//!             "mad_nanos": 521.0,
+//!             "cv": 0.0003893591082833247,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -243,6 +357,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 2566417,
 //!             "min_sec": 0.002566417,
 //!             "min_str": "00:00:00.002",
@@ -254,7 +369,42 @@
 //!             "median_str": "00:00:00.002",
 //!             "std_dev": 7070.360705084288,
 //!             "std_dev_sec": 7.0703607050842884e-6,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 2571416.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 2566916.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 2568916.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 2573916.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 2575416.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 2575916.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 2576316.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 4999.0,
+//!             "mad_nanos": 4999.0,
+//!             "cv": 0.002749597626477192,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -263,6 +413,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 2928666,
 //!             "min_sec": 0.002928666,
 //!             "min_str": "00:00:00.002",
@@ -274,7 +425,42 @@
 //!             "median_str": "00:00:00.003",
 //!             "std_dev": 174125.75197396852,
 //!             "std_dev_sec": 0.00017412575197396852,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 3051791.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 2940978.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 2990228.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 3113354.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 3150291.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 3162604.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 3172454.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 123125.0,
+//!             "mad_nanos": 123125.0,
+//!             "cv": 0.057056896571724684,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -283,6 +469,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 3377791,
 //!             "min_sec": 0.003377791,
 //!             "min_str": "00:00:00.003",
@@ -294,7 +481,42 @@
 //!             "median_str": "00:00:00.003",
 //!             "std_dev": 287675.0802172479,
 //!             "std_dev_sec": 0.00028767508021724787,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 3581208.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 3398132.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 3479499.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 3682916.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 3743941.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 3764283.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 3780556.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 203417.0,
+//!             "mad_nanos": 203417.0,
+//!             "cv": 0.08032906220952479,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -303,6 +525,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 4404499,
 //!             "min_sec": 0.004404499,
 //!             "min_str": "00:00:00.004",
@@ -314,7 +537,42 @@
 //!             "median_str": "00:00:00.004",
 //!             "std_dev": 361361.26367113565,
 //!             "std_dev_sec": 0.00036136126367113564,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 4660020.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 4430051.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 4532259.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 4787780.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 4864436.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 4889988.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 4910430.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 255521.0,
+//!             "mad_nanos": 255521.0,
+//!             "cv": 0.07754500274057528,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -323,6 +581,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 4882584,
 //!             "min_sec": 0.004882584,
 //!             "min_str": "00:00:00.004",
@@ -334,7 +593,42 @@
 //!             "median_str": "00:00:00.004",
 //!             "std_dev": 103679.53179147754,
 //!             "std_dev_sec": 0.00010367953179147753,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 4955896.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 4889915.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 4919240.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 4992552.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 5014546.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 5021877.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 5027742.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 73312.0,
+//!             "mad_nanos": 73312.0,
+//!             "cv": 0.020920439277026373,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -343,6 +637,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 5638959,
 //!             "min_sec": 0.005638959,
 //!             "min_str": "00:00:00.005",
@@ -354,7 +649,42 @@
 //!             "median_str": "00:00:00.005",
 //!             "std_dev": 3151.5749237484424,
 //!             "std_dev_sec": 3.1515749237484423e-6,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 5641187.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 5639181.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 5640073.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 5642301.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 5642970.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 5643193.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 5643371.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 2228.0,
+//!             "mad_nanos": 2228.0,
+//!             "cv": 0.0005586722518527247,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -363,6 +693,7 @@
 //!             "name": "benchmark-workload-2",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 5086250,
 //!             "min_sec": 0.00508625,
 //!             "min_str": "00:00:00.005",
@@ -374,10 +705,46 @@
 //!             "median_str": "00:00:00.005",
 //!             "std_dev": 354290.19585927017,
 //!             "std_dev_sec": 0.00035429019585927015,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 5336771.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 5111302.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 5211510.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 5462031.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 5537187.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 5562239.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 5582281.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 250521.0,
+//!             "mad_nanos": 250521.0,
+//!             "cv": 0.06638662139695899,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ]
-//!       ]
+//!       ],
+//!       "failures": []
 //!     },
 //!     "benchmark-workload-1": {
 //!       "name": "benchmark-workload-1",
@@ -389,6 +756,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 1259375,
 //!             "min_sec": 0.001259375,
 //!             "min_str": "00:00:00.001",
@@ -400,7 +768,42 @@
 //!             "median_str": "00:00:00.001",
 //!             "std_dev": 11844.038584874672,
 //!             "std_dev_sec": 0.000011844038584874672,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 1267750.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 1260212.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 1263562.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 1271937.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 1274450.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 1275287.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 1275957.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 8375.0,
+//!             "mad_nanos": 8375.0,
+//!             "cv": 0.009342566424669432,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -409,6 +812,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 2513584,
 //!             "min_sec": 0.002513584,
 //!             "min_str": "00:00:00.002",
@@ -420,7 +824,42 @@
 //!             "median_str": "00:00:00.002",
 //!             "std_dev": 5155.515541631118,
 //!             "std_dev_sec": 5.155515541631118e-6,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 2517229.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 2513948.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 2515406.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 2519052.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 2520145.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 2520510.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 2520802.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 3645.0,
+//!             "mad_nanos": 3645.0,
+//!             "cv": 0.002048091181845405,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -429,6 +868,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 3755750,
 //!             "min_sec": 0.00375575,
 //!             "min_str": "00:00:00.003",
@@ -440,7 +880,42 @@
 //!             "median_str": "00:00:00.003",
 //!             "std_dev": 19975.766568519968,
 //!             "std_dev_sec": 0.00001997576656851997,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 3769875.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 3757162.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 3762812.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 3776937.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 3781175.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 3782587.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 3783717.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 14125.0,
+//!             "mad_nanos": 14125.0,
+//!             "cv": 0.005298787511129671,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -449,6 +924,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 5003833,
 //!             "min_sec": 0.005003833,
 //!             "min_str": "00:00:00.005",
@@ -460,7 +936,42 @@
 //!             "median_str": "00:00:00.005",
 //!             "std_dev": 18562.26011292806,
 //!             "std_dev_sec": 0.00001856226011292806,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 5016958.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 5005145.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 5010395.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 5023521.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 5027458.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 5028771.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 5029821.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 13125.0,
+//!             "mad_nanos": 13125.0,
+//!             "cv": 0.003699903061372355,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -469,6 +980,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 5518500,
 //!             "min_sec": 0.0055185,
 //!             "min_str": "00:00:00.005",
@@ -480,7 +992,42 @@
 //!             "median_str": "00:00:00.005",
 //!             "std_dev": 540642.5311867353,
 //!             "std_dev_sec": 0.0005406425311867353,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 5900792.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 5556729.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 5709646.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 6091938.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 6206625.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 6244854.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 6275438.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 382292.0,
+//!             "mad_nanos": 382292.0,
+//!             "cv": 0.09162202822718293,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -489,6 +1036,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 6942292,
 //!             "min_sec": 0.006942292,
 //!             "min_str": "00:00:00.006",
@@ -500,7 +1048,42 @@
 //!             "median_str": "00:00:00.007",
 //!             "std_dev": 423674.3416564189,
 //!             "std_dev_sec": 0.00042367434165641895,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 7241875.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 6972250.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 7092083.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 7391666.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 7481541.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 7511499.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 7535466.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 299583.0,
+//!             "mad_nanos": 299583.0,
+//!             "cv": 0.058503404388562205,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -509,6 +1092,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 8784417,
 //!             "min_sec": 0.008784417,
 //!             "min_str": "00:00:00.008",
@@ -520,7 +1104,42 @@
 //!             "median_str": "00:00:00.008",
 //!             "std_dev": 20122.84477900677,
 //!             "std_dev_sec": 0.00002012284477900677,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 8798646.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 8785839.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 8791531.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 8805760.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 8810029.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 8811452.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 8812590.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 14229.0,
+//!             "mad_nanos": 14229.0,
+//!             "cv": 0.0022870387988114045,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -529,6 +1148,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 9426875,
 //!             "min_sec": 0.009426875,
 //!             "min_str": "00:00:00.009",
@@ -540,7 +1160,42 @@
 //!             "median_str": "00:00:00.010",
 //!             "std_dev": 1533891.3850889183,
 //!             "std_dev_sec": 0.0015338913850889183,
-//!             "std_dev_str": "00:00:00.001"
+//!             "std_dev_str": "00:00:00.001",
+//!             "mean_nanos": 10511500.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 9535337.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 9969187.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 11053812.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 11379200.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 11487662.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 11574432.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 1084625.0,
+//!             "mad_nanos": 1084625.0,
+//!             "cv": 0.1459250711210501,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -549,6 +1204,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 9498333,
 //!             "min_sec": 0.009498333,
 //!             "min_str": "00:00:00.009",
@@ -560,7 +1216,42 @@
 //!             "median_str": "00:00:00.009",
 //!             "std_dev": 321733.5854398791,
 //!             "std_dev_sec": 0.0003217335854398791,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 9725833.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 9521083.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 9612083.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 9839583.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 9907833.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 9930583.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 9948783.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 227500.0,
+//!             "mad_nanos": 227500.0,
+//!             "cv": 0.033080311520861924,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ],
 //!         [
@@ -569,6 +1260,7 @@
 //!             "name": "benchmark-workload-1",
 //!             "ramp_up": 1,
 //!             "repeat": 2,
+//!             "batch": 1,
 //!             "min_nanos": 11674000,
 //!             "min_sec": 0.011674,
 //!             "min_str": "00:00:00.011",
@@ -580,10 +1272,46 @@
 //!             "median_str": "00:00:00.012",
 //!             "std_dev": 673990.849303238,
 //!             "std_dev_sec": 0.0006739908493032379,
-//!             "std_dev_str": "00:00:00.000"
+//!             "std_dev_str": "00:00:00.000",
+//!             "mean_nanos": 12150583.0,
+//!             "percentiles": [
+//!               {
+//!                 "percentile": 5.0,
+//!                 "nanos": 11721658.0
+//!               },
+//!               {
+//!                 "percentile": 25.0,
+//!                 "nanos": 11912291.0
+//!               },
+//!               {
+//!                 "percentile": 75.0,
+//!                 "nanos": 12388875.0
+//!               },
+//!               {
+//!                 "percentile": 90.0,
+//!                 "nanos": 12531850.0
+//!               },
+//!               {
+//!                 "percentile": 95.0,
+//!                 "nanos": 12579508.0
+//!               },
+//!               {
+//!                 "percentile": 99.0,
+//!                 "nanos": 12617635.0
+//!               }
+//!             ],
+//!             "iqr_nanos": 476583.0,
+//!             "mad_nanos": 476583.0,
+//!             "cv": 0.05546983396338439,
+//!             "outliers": {
+//!               "policy": "Include",
+//!               "mild": 0,
+//!               "severe": 0
+//!             }
 //!           }
 //!         ]
-//!       ]
+//!       ],
+//!       "failures": []
 //!     }
 //!   }
 //! }
@@ -595,32 +1323,32 @@
 //!
 //! Benchmark name: benchmark-workload-2
 //! ```csv
-//! point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec
-//! 1,1,2,0.00063575,0.000637376,0.000636563,0.0000011497556262093261
-//! 2,1,2,0.001265333,0.001269584,0.001267458,0.0000030059109268240135
-//! 3,1,2,0.001890958,0.001892333,0.001891645,0.0000009722718241315028
-//! 4,1,2,0.002512042,0.002604791,0.002558416,0.0000655834468482711
-//! 5,1,2,0.003129084,0.00314525,0.003137167,0.000011431088224661727
-//! 6,1,2,0.003092583,0.003767833,0.003430208,0.0004774738539962162
-//! 7,1,2,0.004055333,0.004084166,0.004069749,0.000020388009821951726
-//! 8,1,2,0.004171542,0.005756541,0.004964041,0.0011207635410738967
-//! 9,1,2,0.004549334,0.005149792,0.004849563,0.0004245879236177119
-//! 10,1,2,0.006351,0.007404083,0.006877541,0.000744642130452273
+//! point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec,status
+//! 1,1,2,0.00063575,0.000637376,0.000636563,0.0000011497556262093261,ok
+//! 2,1,2,0.001265333,0.001269584,0.001267458,0.0000030059109268240135,ok
+//! 3,1,2,0.001890958,0.001892333,0.001891645,0.0000009722718241315028,ok
+//! 4,1,2,0.002512042,0.002604791,0.002558416,0.0000655834468482711,ok
+//! 5,1,2,0.003129084,0.00314525,0.003137167,0.000011431088224661727,ok
+//! 6,1,2,0.003092583,0.003767833,0.003430208,0.0004774738539962162,ok
+//! 7,1,2,0.004055333,0.004084166,0.004069749,0.000020388009821951726,ok
+//! 8,1,2,0.004171542,0.005756541,0.004964041,0.0011207635410738967,ok
+//! 9,1,2,0.004549334,0.005149792,0.004849563,0.0004245879236177119,ok
+//! 10,1,2,0.006351,0.007404083,0.006877541,0.000744642130452273,ok
 //! ```
 //!
 //! Benchmark name: benchmark-workload-1
 //! ```csv
-//! point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec
-//! 1,1,2,0.001265417,0.001266709,0.001266063,0.0000009135819612930194
-//! 2,1,2,0.002518501,0.002534999,0.00252675,0.000011665847676015662
-//! 3,1,2,0.003762958,0.004072459,0.003917708,0.00021885025588401765
-//! 4,1,2,0.004471833,0.004559375,0.004515604,0.00006190154183863275
-//! 5,1,2,0.005676917,0.005765751,0.005721334,0.00006281512379992577
-//! 6,1,2,0.006610875,0.007539833,0.007075354,0.0006568725012374928
-//! 7,1,2,0.007831792,0.008021959,0.007926875,0.0001344683752579022
-//! 8,1,2,0.009668583,0.009832959,0.009750771,0.00011623138426431993
-//! 9,1,2,0.009677333,0.011178501,0.010427917,0.0010614860725002473
-//! 10,1,2,0.011440417,0.012526,0.011983208,0.0007676231008408358
+//! point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec,status
+//! 1,1,2,0.001265417,0.001266709,0.001266063,0.0000009135819612930194,ok
+//! 2,1,2,0.002518501,0.002534999,0.00252675,0.000011665847676015662,ok
+//! 3,1,2,0.003762958,0.004072459,0.003917708,0.00021885025588401765,ok
+//! 4,1,2,0.004471833,0.004559375,0.004515604,0.00006190154183863275,ok
+//! 5,1,2,0.005676917,0.005765751,0.005721334,0.00006281512379992577,ok
+//! 6,1,2,0.006610875,0.007539833,0.007075354,0.0006568725012374928,ok
+//! 7,1,2,0.007831792,0.008021959,0.007926875,0.0001344683752579022,ok
+//! 8,1,2,0.009668583,0.009832959,0.009750771,0.00011623138426431993,ok
+//! 9,1,2,0.009677333,0.011178501,0.010427917,0.0010614860725002473,ok
+//! 10,1,2,0.011440417,0.012526,0.011983208,0.0007676231008408358,ok
 //! ```
 //!
 //! </details>
@@ -733,98 +1461,139 @@
 //! <details>
 //!  <summary>Find Regressions Output</summary>
 //!  
-//! Analysis result. Current and previous values are in nanosecond units, the change is in percents.
+//! Analysis result. Current and previous values are values of the compared metric, nanoseconds of the
+//! median by default, the change is in percents.
 //! ```json
 //!   {
 //!     "name": "benchmarks",
+//!     "metric": "Median",
 //!     "new_series": [
 //!       "benchmark-2"
 //!     ],
+//!     "removed_series": [],
+//!     "new_points": {},
+//!     "removed_points": {},
 //!     "equal_series": {},
 //!     "divergent_series": {
 //!       "benchmark-1": {
 //!         "8": {
 //!           "Equal": {
 //!             "point": "8",
-//!             "previous": 200686042,
-//!             "current": 206411375,
-//!             "change": 2.852880520709064
+//!             "previous": 200686042.0,
+//!             "current": 206411375.0,
+//!             "change": 2.852880520709064,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "7": {
 //!           "Equal": {
 //!             "point": "7",
-//!             "previous": 176700166,
-//!             "current": 183395333,
-//!             "change": 3.788998704166474
+//!             "previous": 176700166.0,
+//!             "current": 183395333.0,
+//!             "change": 3.788998704166474,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "10": {
 //!           "Equal": {
 //!             "point": "10",
-//!             "previous": 251701083,
-//!             "current": 256856000,
-//!             "change": 2.0480313149864315
+//!             "previous": 251701083.0,
+//!             "current": 256856000.0,
+//!             "change": 2.0480313149864315,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "2": {
 //!           "Equal": {
 //!             "point": "2",
-//!             "previous": 52684667,
-//!             "current": 55052334,
-//!             "change": 4.494034288951653
+//!             "previous": 52684667.0,
+//!             "current": 55052334.0,
+//!             "change": 4.494034288951653,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "5": {
 //!           "Equal": {
 //!             "point": "5",
-//!             "previous": 127510583,
-//!             "current": 130817709,
-//!             "change": 2.5936090340046434
+//!             "previous": 127510583.0,
+//!             "current": 130817709.0,
+//!             "change": 2.5936090340046434,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "6": {
 //!           "Equal": {
 //!             "point": "6",
-//!             "previous": 152803084,
-//!             "current": 158141750,
-//!             "change": 3.4938208446106955
+//!             "previous": 152803084.0,
+//!             "current": 158141750.0,
+//!             "change": 3.4938208446106955,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "9": {
 //!           "Equal": {
 //!             "point": "9",
-//!             "previous": 225706250,
-//!             "current": 229522083,
-//!             "change": 1.6906191122310474
+//!             "previous": 225706250.0,
+//!             "current": 229522083.0,
+//!             "change": 1.6906191122310474,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "1": {
 //!           "Greater": {
 //!             "point": "1",
-//!             "previous": 26413209,
-//!             "current": 35264166,
-//!             "change": 33.50958605597674
+//!             "previous": 26413209.0,
+//!             "current": 35264166.0,
+//!             "change": 33.50958605597674,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "4": {
 //!           "Equal": {
 //!             "point": "4",
-//!             "previous": 104201208,
-//!             "current": 109166958,
-//!             "change": 4.7655397622645665
+//!             "previous": 104201208.0,
+//!             "current": 109166958.0,
+//!             "change": 4.7655397622645665,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         },
 //!         "3": {
 //!           "Equal": {
 //!             "point": "3",
-//!             "previous": 79801875,
-//!             "current": 84060834,
-//!             "change": 5.336915955922095
+//!             "previous": 79801875.0,
+//!             "current": 84060834.0,
+//!             "change": 5.336915955922095,
+//!             "metric": "Median",
+//!             "p_value": null,
+//!             "effect_size": null
 //!           }
 //!         }
 //!       }
-//!     }
+//!     },
+//!     "regressed_series": [
+//!       "benchmark-1"
+//!     ],
+//!     "improved_series": [],
+//!     "mixed_series": [],
+//!     "failed_series": {}
 //!   }
 //! ```
 //! </details>
@@ -838,8 +1607,20 @@ pub mod analysis_result;
 pub mod benchmark;
 pub mod benchmark_comparison;
 pub mod benchmarks;
+pub mod bootstrap;
+pub mod comparison;
 pub mod disk_usage;
+pub mod failure;
+pub mod filter;
+pub mod harness;
+pub mod isolation;
+pub mod metric;
+pub mod outliers;
+pub mod repetition;
 pub mod run_summary;
 pub mod series_summary;
+mod statistics;
 pub mod stopwatch;
 pub mod summary;
+pub mod threading;
+pub mod threshold;
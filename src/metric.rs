@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::{anyhow, Error};
+use serde::{Deserialize, Serialize};
+
+use crate::run_summary::RunSummary;
+use crate::statistics;
+
+/// Statistic of a workload point compared by [crate::comparison::compare_runs]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Metric {
+    /// Minimum duration
+    Min,
+    /// Median duration
+    #[default]
+    Median,
+    /// Mean duration
+    Mean,
+    /// Duration at a percentile between 0 and 100, like 99 for the tail latency
+    Percentile(f64),
+    /// Invocations per second at the median duration
+    Throughput,
+}
+
+impl Metric {
+    /// Name of the metric on the command line: `min`, `median`, `mean`, `p<PERCENTILE>` or
+    /// `throughput`
+    pub fn name(&self) -> String {
+        match self {
+            Metric::Min => "min".to_string(),
+            Metric::Median => "median".to_string(),
+            Metric::Mean => "mean".to_string(),
+            Metric::Percentile(percentile) => format!("p{}", percentile),
+            Metric::Throughput => "throughput".to_string(),
+        }
+    }
+
+    /// Parse the name of the metric, see [Self::name]
+    pub fn from_name(name: &str) -> Result<Metric, Error> {
+        match name {
+            "min" => Ok(Metric::Min),
+            "median" => Ok(Metric::Median),
+            "mean" => Ok(Metric::Mean),
+            "throughput" => Ok(Metric::Throughput),
+            name => match name.strip_prefix('p').map(|p| p.parse::<f64>()) {
+                Some(Ok(percentile)) if (0.0..=100.0).contains(&percentile) => {
+                    Ok(Metric::Percentile(percentile))
+                }
+                _ => Err(anyhow!("Unknown metric: {}", name)),
+            },
+        }
+    }
+
+    /// Unit of the values of the metric
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Metric::Throughput => "ops/s",
+            _ => "ns",
+        }
+    }
+
+    /// Check if greater values of the metric are better, true for [Metric::Throughput]
+    pub fn higher_is_better(&self) -> bool {
+        matches!(self, Metric::Throughput)
+    }
+
+    /// Get the value of the metric for `run`, computed from the retained samples when
+    /// `from_samples` is set and from the reported statistics otherwise. Samples excluded by the
+    /// [crate::outliers::OutlierPolicy] of the run are not taken into account.
+    ///
+    /// Returns `None` when the run has no value of the metric, like a percentile that was not
+    /// computed or the mean of a summary saved before the mean was recorded.
+    pub(crate) fn value(&self, run: &RunSummary, from_samples: bool) -> Option<f64> {
+        let mut sorted: Vec<f64> = if from_samples {
+            run.included_samples()
+        } else {
+            vec![]
+        };
+        sorted.sort_by(f64::total_cmp);
+        let median = if sorted.is_empty() {
+            run.exact_median_nanos()
+        } else {
+            statistics::median(&sorted)
+        };
+        match self {
+            Metric::Min => Some(sorted.first().copied().unwrap_or(run.exact_min_nanos())),
+            Metric::Median => Some(median),
+            Metric::Mean if !sorted.is_empty() => {
+                Some(sorted.iter().sum::<f64>() / sorted.len() as f64)
+            }
+            // summaries saved before the mean was recorded read it as zero
+            Metric::Mean => Some(run.mean_nanos()).filter(|mean| *mean > 0.0 || median == 0.0),
+            Metric::Percentile(percentile) if !sorted.is_empty() => {
+                Some(statistics::percentile(&sorted, *percentile))
+            }
+            Metric::Percentile(percentile) => run
+                .percentiles()
+                .iter()
+                .find(|p| p.percentile() == *percentile)
+                .map(|p| p.nanos()),
+            Metric::Throughput => Some(1e9 / median),
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
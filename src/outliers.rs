@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use crate::statistics;
+
+/// Policy for the samples of a workload point that lie outside the Tukey fences
+///
+/// A sample is a mild outlier when it lies more than 1.5 interquartile ranges below the first
+/// quartile or above the third quartile, and a severe outlier when it lies more than 3
+/// interquartile ranges away.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutlierPolicy {
+    /// Report statistics of all samples
+    #[default]
+    Include,
+    /// Exclude severe outliers from the reported statistics and from comparisons
+    ExcludeSevere,
+    /// Exclude mild and severe outliers from the reported statistics and from comparisons
+    ExcludeAll,
+}
+
+impl OutlierPolicy {
+    /// Classify `samples` and return the outlier counts together with the samples that the
+    /// statistics are reported for
+    pub(crate) fn apply(self, samples: &[f64]) -> (Outliers, Vec<f64>) {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let (q1, q3) = (
+            statistics::percentile(&sorted, 25.0),
+            statistics::percentile(&sorted, 75.0),
+        );
+        let outside =
+            |sample: f64, k: f64| sample < q1 - k * (q3 - q1) || sample > q3 + k * (q3 - q1);
+        let mut outliers = Outliers {
+            policy: self,
+            mild: 0,
+            severe: 0,
+        };
+        let mut included = Vec::with_capacity(samples.len());
+        for &sample in samples {
+            let excluded = if outside(sample, 3.0) {
+                outliers.severe += 1;
+                self != OutlierPolicy::Include
+            } else if outside(sample, 1.5) {
+                outliers.mild += 1;
+                self == OutlierPolicy::ExcludeAll
+            } else {
+                false
+            };
+            if !excluded {
+                included.push(sample);
+            }
+        }
+        (outliers, included)
+    }
+}
+
+/// Outliers among the samples of a workload point, see [OutlierPolicy]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Outliers {
+    policy: OutlierPolicy,
+    mild: usize,
+    severe: usize,
+}
+
+impl Outliers {
+    /// Policy the statistics of the workload point were reported with
+    pub fn policy(&self) -> OutlierPolicy {
+        self.policy
+    }
+
+    /// Number of mild outliers
+    pub fn mild(&self) -> usize {
+        self.mild
+    }
+
+    /// Number of severe outliers
+    pub fn severe(&self) -> usize {
+        self.severe
+    }
+
+    /// Number of samples excluded from the reported statistics
+    pub fn excluded(&self) -> usize {
+        match self.policy {
+            OutlierPolicy::Include => 0,
+            OutlierPolicy::ExcludeSevere => self.severe,
+            OutlierPolicy::ExcludeAll => self.mild + self.severe,
+        }
+    }
+}
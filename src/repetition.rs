@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+/// Policy that decides how many times a benchmark is repeated for each workload point
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Repetition {
+    /// Repeat the benchmark `repeat` times, as provided to [crate::benchmarks::Benchmarks::add]
+    #[default]
+    Fixed,
+    /// Repeat the benchmark until the 95% confidence interval of the median is narrower than
+    /// `precision` percent of the median or until `time_budget` is exhausted. The number of
+    /// repetitions is never less than `min_repeat` and never more than `max_repeat`. The `repeat`
+    /// value provided to [crate::benchmarks::Benchmarks::add] is ignored, the `repeat` of the
+    /// [crate::run_summary::RunSummary] records the actual number of repetitions.
+    Adaptive {
+        /// Requested width of the confidence interval of the median, in percents of the median
+        precision: f64,
+        /// Time budget for the measured iterations of a single workload point
+        time_budget: Duration,
+        /// Minimum number of repetitions
+        min_repeat: usize,
+        /// Maximum number of repetitions
+        max_repeat: usize,
+    },
+}
+
+impl Repetition {
+    /// Create an adaptive [Repetition]
+    ///
+    /// * `precision` - requested width of the confidence interval of the median, in percents
+    /// * `time_budget` - time budget for each workload point
+    /// * `min_repeat` - minimum number of repetitions
+    /// * `max_repeat` - maximum number of repetitions
+    pub fn adaptive(
+        precision: f64,
+        time_budget: Duration,
+        min_repeat: usize,
+        max_repeat: usize,
+    ) -> Repetition {
+        Repetition::Adaptive {
+            precision,
+            time_budget,
+            min_repeat,
+            max_repeat,
+        }
+    }
+}
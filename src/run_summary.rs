@@ -1,6 +1,33 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
+use anyhow::{anyhow, Context, Error};
+use chrono::{DateTime, Utc};
 use num_traits::cast::ToPrimitive;
 use serde::{Deserialize, Serialize};
+use statrs::statistics::{Data, Distribution, Max, Median, Min};
+
+use crate::benchmark::DEFAULT_PERCENTILES;
+use crate::bootstrap::{Bootstrap, ConfidenceInterval};
+use crate::outliers::{OutlierPolicy, Outliers};
+use crate::statistics;
+
+/// Options of the statistics computed for each workload point
+#[derive(Debug, Clone)]
+pub(crate) struct SummaryOptions {
+    pub(crate) percentiles: Vec<f64>,
+    pub(crate) retain_samples: bool,
+    pub(crate) outlier_policy: OutlierPolicy,
+    pub(crate) bootstrap: Option<Bootstrap>,
+}
+
+impl Default for SummaryOptions {
+    fn default() -> Self {
+        SummaryOptions {
+            percentiles: DEFAULT_PERCENTILES.to_vec(),
+            retain_samples: false,
+            outlier_policy: OutlierPolicy::Include,
+            bootstrap: None,
+        }
+    }
+}
 
 /// Result of single workload point run
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,6 +35,8 @@ pub struct RunSummary {
     name: String,
     ramp_up: usize,
     repeat: usize,
+    #[serde(default = "RunSummary::default_batch")]
+    batch: usize,
     min_nanos: u64,
     min_sec: f64,
     min_str: String,
@@ -20,61 +49,373 @@ pub struct RunSummary {
     std_dev: Option<f64>,
     std_dev_sec: Option<f64>,
     std_dev_str: String,
+    #[serde(default)]
+    mean_nanos: f64,
+    #[serde(default)]
+    percentiles: Vec<Percentile>,
+    #[serde(default)]
+    iqr_nanos: f64,
+    #[serde(default)]
+    mad_nanos: f64,
+    #[serde(default)]
+    cv: Option<f64>,
+    #[serde(default)]
+    outliers: Outliers,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    median_ci: Option<ConfidenceInterval>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mean_ci: Option<ConfidenceInterval>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    raw_samples: Vec<Sample>,
+}
+
+/// Measured duration of a single invocation in one iteration of the benchmark
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sample {
+    iteration: usize,
+    nanos: f64,
+}
+
+impl Sample {
+    pub(crate) fn new(iteration: usize, nanos: f64) -> Sample {
+        Sample { iteration, nanos }
+    }
+
+    /// Index of the iteration of the workload point, counting ramp up and batch calibration
+    /// iterations
+    pub fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    /// Duration of a single invocation in nanoseconds
+    pub fn nanos(&self) -> f64 {
+        self.nanos
+    }
+}
+
+/// Duration at a percentile of the measured samples
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Percentile {
+    percentile: f64,
+    nanos: f64,
+}
+
+impl Percentile {
+    /// The percentile, between 0 and 100
+    pub fn percentile(&self) -> f64 {
+        self.percentile
+    }
+
+    /// Duration at the percentile in nanoseconds
+    pub fn nanos(&self) -> f64 {
+        self.nanos
+    }
+
+    /// Label of the percentile as it appears in CSV headers, like `p95`
+    pub fn label(&self) -> String {
+        format!("p{}", self.percentile)
+    }
 }
 
 impl RunSummary {
+    /// Create a [RunSummary] from the measured durations of the benchmark
+    ///
+    /// The `repeat` of the summary is the number of measured samples, which differs from the
+    /// configured number of repetitions under [crate::repetition::Repetition::Adaptive]. The
+    /// durations in seconds and the statistics in fractional nanoseconds keep the precision of
+    /// the batched samples, the minimum, maximum and median in whole nanoseconds are rounded.
+    ///
+    /// * `batch` - number of invocations in each sample
+    /// * `samples` - measured durations of a single invocation
+    /// * `options` - percentiles to compute, outlier policy, bootstrap configuration and retention
+    ///   of the samples
     pub(crate) fn new(
         name: String,
         ramp_up: usize,
-        repeat: usize,
-        min: u64,
-        max: u64,
-        median: u64,
-        std_dev: Option<f64>,
+        batch: usize,
+        samples: Vec<Sample>,
+        options: &SummaryOptions,
     ) -> RunSummary {
+        let durations: Vec<f64> = samples.iter().map(|sample| sample.nanos).collect();
+        let (outliers, durations) = options.outlier_policy.apply(&durations);
+        let data = Data::new(durations.clone());
+        let (min, max, median, std_dev, mean) = (
+            data.min(),
+            data.max(),
+            data.median(),
+            // NaN for a single sample
+            data.std_dev().filter(|std_dev| !std_dev.is_nan()),
+            data.mean().unwrap_or(f64::NAN),
+        );
+        let (median_ci, mean_ci) = match options.bootstrap {
+            Some(bootstrap) => {
+                let ((median_lower, median_upper), (mean_lower, mean_upper)) =
+                    statistics::bootstrap(
+                        &durations,
+                        bootstrap.confidence(),
+                        bootstrap.resamples(),
+                        bootstrap.seed(),
+                    );
+                (
+                    Some(ConfidenceInterval::new(
+                        bootstrap.confidence(),
+                        median_lower,
+                        median_upper,
+                    )),
+                    Some(ConfidenceInterval::new(
+                        bootstrap.confidence(),
+                        mean_lower,
+                        mean_upper,
+                    )),
+                )
+            }
+            None => (None, None),
+        };
+        let mut sorted = durations;
+        sorted.sort_by(f64::total_cmp);
         RunSummary {
             name,
             ramp_up,
+            repeat: samples.len(),
+            batch,
+            min_nanos: min.round() as u64,
+            min_sec: min / 1e9,
+            min_str: Self::format_elapsed_nanos(min.round() as u64),
+            max_nanos: max.round() as u64,
+            max_sec: max / 1e9,
+            max_str: Self::format_elapsed_nanos(max.round() as u64),
+            median_nanos: median.round() as u64,
+            median_sec: median / 1e9,
+            median_str: Self::format_elapsed_nanos(median.round() as u64),
+            std_dev,
+            std_dev_sec: std_dev.map(|x| x / 1e9),
+            std_dev_str: Self::format_std_dev_nanos(std_dev),
+            mean_nanos: mean,
+            percentiles: options
+                .percentiles
+                .iter()
+                .map(|&percentile| Percentile {
+                    percentile,
+                    nanos: statistics::percentile(&sorted, percentile),
+                })
+                .collect(),
+            iqr_nanos: statistics::percentile(&sorted, 75.0)
+                - statistics::percentile(&sorted, 25.0),
+            mad_nanos: statistics::median_absolute_deviation(&sorted),
+            cv: std_dev.filter(|_| mean > 0.0).map(|std_dev| std_dev / mean),
+            outliers,
+            median_ci,
+            mean_ci,
+            raw_samples: if options.retain_samples {
+                samples
+            } else {
+                vec![]
+            },
+        }
+    }
+
+    /// Create a [RunSummary] from the columns written by [Self::as_csv]
+    ///
+    /// The number of samples is taken from the `repeat` column. An empty or NaN `std_dev_sec` is
+    /// read as no standard deviation. CSV does not hold the batch size, it is set to 1. The
+    /// optional statistics columns are not read.
+    pub(crate) fn from_csv(name: String, columns: &[&str]) -> Result<RunSummary, Error> {
+        let [ramp_up, repeat, min_sec, max_sec, median_sec, std_dev_sec] = columns else {
+            return Err(anyhow!(
+                "Expected {} columns: {}",
+                RunSummary::csv_headers(),
+                columns.join(",")
+            ));
+        };
+        let seconds = |sec: &str| -> Result<f64, Error> {
+            sec.trim()
+                .parse()
+                .with_context(|| anyhow!("Invalid duration: {}", sec))
+        };
+        let (min_sec, max_sec, median_sec) =
+            (seconds(min_sec)?, seconds(max_sec)?, seconds(median_sec)?);
+        let nanos = |sec: f64| (sec * 1e9).round() as u64;
+        let (min, max, median) = (nanos(min_sec), nanos(max_sec), nanos(median_sec));
+        // a single sample has no standard deviation
+        let std_dev_sec: Option<f64> = match std_dev_sec.trim() {
+            "" => None,
+            sec => Some(
+                sec.parse::<f64>()
+                    .with_context(|| anyhow!("Invalid standard deviation: {}", sec))?,
+            )
+            .filter(|sec| !sec.is_nan()),
+        };
+        let std_dev = std_dev_sec.map(|sec| sec * 1e9);
+        let repeat = repeat
+            .trim()
+            .parse()
+            .with_context(|| anyhow!("Invalid repeat: {}", repeat))?;
+        Ok(RunSummary {
+            name,
+            ramp_up: ramp_up
+                .trim()
+                .parse()
+                .with_context(|| anyhow!("Invalid ramp up: {}", ramp_up))?,
             repeat,
+            batch: Self::default_batch(),
             min_nanos: min,
-            min_sec: min as f64 / 1e9,
+            min_sec,
             min_str: Self::format_elapsed_nanos(min),
             max_nanos: max,
-            max_sec: max as f64 / 1e9,
+            max_sec,
             max_str: Self::format_elapsed_nanos(max),
             median_nanos: median,
-            median_sec: median as f64 / 1e9,
+            median_sec,
             median_str: Self::format_elapsed_nanos(median),
             std_dev,
-            std_dev_sec: std_dev.map(|x| x / 1e9),
+            std_dev_sec,
             std_dev_str: Self::format_std_dev_nanos(std_dev),
-        }
+            mean_nanos: 0.0,
+            percentiles: vec![],
+            iqr_nanos: 0.0,
+            mad_nanos: 0.0,
+            cv: None,
+            outliers: Outliers::default(),
+            median_ci: None,
+            mean_ci: None,
+            raw_samples: vec![],
+        })
     }
 
     pub fn name(&self) -> &String {
         &self.name
     }
 
+    /// Number of measured samples. For [crate::repetition::Repetition::Adaptive] this is the
+    /// actual number of repetitions rather than the configured one.
+    pub fn repeat(&self) -> usize {
+        self.repeat
+    }
+
+    /// Number of invocations of the benchmark function in each sample. The reported durations
+    /// are durations of a single invocation.
+    pub fn batch(&self) -> usize {
+        self.batch
+    }
+
+    /// Minimum duration in whole nanoseconds, rounded
     pub fn min_nanos(&self) -> u64 {
         self.min_nanos
     }
 
+    /// Minimum duration in seconds, with the fractions of a nanosecond of batched samples
+    pub fn min_sec(&self) -> f64 {
+        self.min_sec
+    }
+
+    /// Maximum duration in whole nanoseconds, rounded
     pub fn max_nanos(&self) -> u64 {
         self.max_nanos
     }
 
+    /// Maximum duration in seconds, with the fractions of a nanosecond of batched samples
+    pub fn max_sec(&self) -> f64 {
+        self.max_sec
+    }
+
+    /// Median duration in whole nanoseconds, rounded
     pub fn median_nanos(&self) -> u64 {
         self.median_nanos
     }
 
+    /// Median duration in seconds, with the fractions of a nanosecond of batched samples
+    pub fn median_sec(&self) -> f64 {
+        self.median_sec
+    }
+
+    /// Minimum duration in nanoseconds, with the fractions of a nanosecond of batched samples
+    pub(crate) fn exact_min_nanos(&self) -> f64 {
+        self.exact_nanos(self.min_nanos, self.min_sec)
+    }
+
+    /// Median duration in nanoseconds, with the fractions of a nanosecond of batched samples
+    pub(crate) fn exact_median_nanos(&self) -> f64 {
+        self.exact_nanos(self.median_nanos, self.median_sec)
+    }
+
+    fn exact_nanos(&self, nanos: u64, sec: f64) -> f64 {
+        if self.batch > 1 {
+            sec * 1e9
+        } else {
+            nanos as f64
+        }
+    }
+
     pub fn std_dev(&self) -> Option<f64> {
         self.std_dev
     }
 
+    /// Mean duration in nanoseconds, zero for summaries produced by earlier versions
+    pub fn mean_nanos(&self) -> f64 {
+        self.mean_nanos
+    }
+
+    /// Durations at the configured percentiles, see
+    /// [crate::benchmarks::Benchmarks::set_percentiles]
+    pub fn percentiles(&self) -> &Vec<Percentile> {
+        &self.percentiles
+    }
+
+    /// Interquartile range, the difference between the 75th and the 25th percentiles, in
+    /// nanoseconds
+    pub fn iqr_nanos(&self) -> f64 {
+        self.iqr_nanos
+    }
+
+    /// Median absolute deviation from the median in nanoseconds
+    pub fn mad_nanos(&self) -> f64 {
+        self.mad_nanos
+    }
+
+    /// Coefficient of variation, the standard deviation divided by the mean
+    pub fn cv(&self) -> Option<f64> {
+        self.cv
+    }
+
+    /// Outliers among the measured samples and the policy the statistics were reported with, see
+    /// [crate::benchmarks::Benchmarks::set_outlier_policy]
+    pub fn outliers(&self) -> &Outliers {
+        &self.outliers
+    }
+
+    /// Bootstrap confidence interval of the median, see
+    /// [crate::benchmarks::Benchmarks::set_bootstrap]
+    pub fn median_ci(&self) -> Option<&ConfidenceInterval> {
+        self.median_ci.as_ref()
+    }
+
+    /// Bootstrap confidence interval of the mean, see
+    /// [crate::benchmarks::Benchmarks::set_bootstrap]
+    pub fn mean_ci(&self) -> Option<&ConfidenceInterval> {
+        self.mean_ci.as_ref()
+    }
+
+    /// Measured samples in the order of measurement, empty unless retained with
+    /// [crate::benchmarks::Benchmarks::set_retain_samples]
+    pub fn raw_samples(&self) -> &Vec<Sample> {
+        &self.raw_samples
+    }
+
+    /// Durations of the retained samples in nanoseconds, without the outliers excluded by the
+    /// policy the statistics were reported with
+    pub(crate) fn included_samples(&self) -> Vec<f64> {
+        let durations: Vec<f64> = self.raw_samples.iter().map(|s| s.nanos()).collect();
+        let (_outliers, included) = self.outliers.policy().apply(&durations);
+        included
+    }
+
+    fn default_batch() -> usize {
+        1
+    }
+
     fn format_elapsed_nanos(t: u64) -> String {
         let (secs, nsecs) = ((t / 1_000_000_000) as i64, (t % 1_000_000_000) as u32);
-        let datetime =
-            DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp_opt(secs, nsecs).unwrap(), Utc);
+        let datetime = DateTime::<Utc>::from_timestamp(secs, nsecs).unwrap();
         datetime.format("%H:%M:%S.%3f").to_string()
     }
 
@@ -85,10 +426,7 @@ impl RunSummary {
                 None => "null".to_string(),
                 Some(t) => {
                     let (secs, nsecs) = ((t / 1_000_000_000), (t % 1_000_000_000) as u32);
-                    let datetime = DateTime::<Utc>::from_utc(
-                        NaiveDateTime::from_timestamp_opt(secs, nsecs).unwrap(),
-                        Utc,
-                    );
+                    let datetime = DateTime::<Utc>::from_timestamp(secs, nsecs).unwrap();
                     datetime.format("%H:%M:%S.%3f").to_string()
                 }
             },
@@ -102,6 +440,47 @@ impl RunSummary {
         )
     }
 
+    /// Headers of the optional statistics columns
+    pub(crate) fn csv_statistics_headers(&self) -> String {
+        let mut headers = vec!["mean_sec".to_string()];
+        headers.extend(
+            self.percentiles
+                .iter()
+                .map(|percentile| format!("{}_sec", percentile.label())),
+        );
+        headers.extend(["iqr_sec", "mad_sec", "cv"].map(String::from));
+        if self.median_ci.is_some() {
+            headers.extend(
+                [
+                    "median_ci_lower_sec",
+                    "median_ci_upper_sec",
+                    "mean_ci_lower_sec",
+                    "mean_ci_upper_sec",
+                ]
+                .map(String::from),
+            );
+        }
+        headers.join(",")
+    }
+
+    /// Values of the optional statistics columns
+    pub(crate) fn as_csv_statistics(&self) -> String {
+        let mut values = vec![(self.mean_nanos / 1e9).to_string()];
+        values.extend(
+            self.percentiles
+                .iter()
+                .map(|percentile| (percentile.nanos / 1e9).to_string()),
+        );
+        values.push((self.iqr_nanos / 1e9).to_string());
+        values.push((self.mad_nanos / 1e9).to_string());
+        values.push(self.cv.map(|cv| cv.to_string()).unwrap_or_default());
+        for ci in [&self.median_ci, &self.mean_ci].into_iter().flatten() {
+            values.push((ci.lower_nanos() / 1e9).to_string());
+            values.push((ci.upper_nanos() / 1e9).to_string());
+        }
+        values.join(",")
+    }
+
     pub(crate) fn as_csv(&self) -> String {
         format!(
             "{},{},{},{},{},{}",
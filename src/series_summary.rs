@@ -1,5 +1,10 @@
+use crate::failure::{Failure, FailureKind};
 use crate::run_summary::RunSummary;
+use anyhow::{anyhow, Context, Error};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
 
 /// Summary of series of runs
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,6 +12,8 @@ pub struct SeriesSummary {
     name: String,
     config: String,
     runs: Vec<(String, RunSummary)>,
+    #[serde(default)]
+    failures: Vec<Failure>,
 }
 
 impl SeriesSummary {
@@ -15,6 +22,7 @@ impl SeriesSummary {
             name,
             config,
             runs: vec![],
+            failures: vec![],
         }
     }
 
@@ -22,35 +30,198 @@ impl SeriesSummary {
         self.runs.push((point, run_summary))
     }
 
-    pub(crate) fn runs(&self) -> &Vec<(String, RunSummary)> {
+    pub(crate) fn add_failure(&mut self, failure: Failure) {
+        self.failures.push(failure)
+    }
+
+    /// Append the runs and the failures of `other`
+    pub(crate) fn merge(&mut self, other: SeriesSummary) {
+        self.runs.extend(other.runs);
+        self.failures.extend(other.failures);
+    }
+
+    /// Get [RunSummary] of each workload point in the order the points were run
+    pub fn runs(&self) -> &Vec<(String, RunSummary)> {
         &self.runs
     }
 
-    fn csv_headers(&self, with_config: bool) -> String {
-        if with_config {
-            format!(
-                "{},{},,configuration: {}",
-                "point",
-                RunSummary::csv_headers(),
-                self.config
+    /// Get failures of workload points that did not complete and of the series setup and
+    /// teardown
+    pub fn failures(&self) -> &Vec<Failure> {
+        &self.failures
+    }
+
+    /// Check if any workload point, the series setup or the series teardown failed
+    pub fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    /// Names of all workload points, both completed and failed
+    pub(crate) fn points(&self) -> HashSet<String> {
+        self.runs
+            .iter()
+            .map(|(point, _run_summary)| point.clone())
+            .chain(
+                self.failures
+                    .iter()
+                    .filter_map(|failure| failure.point().cloned()),
             )
+            .collect()
+    }
+
+    fn csv_headers(&self, with_config: bool, with_statistics: bool) -> String {
+        let mut headers = format!("{},{}", "point", RunSummary::csv_headers());
+        if let Some(statistics_headers) = self.csv_statistics_headers(with_statistics) {
+            headers = format!("{},{}", headers, statistics_headers);
+        }
+        if with_config {
+            format!("{},{},,configuration: {}", headers, "status", self.config)
         } else {
-            format!("{},{}", "point", RunSummary::csv_headers())
+            format!("{},{}", headers, "status")
         }
     }
 
-    pub(crate) fn as_csv(&self, with_headers: bool, with_config: bool) -> Vec<String> {
+    /// Headers of the statistics columns, taken from the first run since all runs of a series
+    /// compute the same percentiles
+    fn csv_statistics_headers(&self, with_statistics: bool) -> Option<String> {
+        self.runs
+            .first()
+            .filter(|_| with_statistics)
+            .map(|(_point, run_summary)| run_summary.csv_statistics_headers())
+    }
+
+    pub(crate) fn as_csv(
+        &self,
+        with_headers: bool,
+        with_config: bool,
+        with_statistics: bool,
+    ) -> Vec<String> {
         let mut result = Vec::new();
         if with_headers {
-            result.push(self.csv_headers(with_config));
+            result.push(self.csv_headers(with_config, with_statistics));
         }
+        let statistics_headers = self.csv_statistics_headers(with_statistics);
         for (point, summary) in &self.runs {
-            result.push(format!("{},{}", point, summary.as_csv()));
+            match statistics_headers {
+                Some(_) => result.push(format!(
+                    "{},{},{},{}",
+                    point,
+                    summary.as_csv(),
+                    summary.as_csv_statistics(),
+                    "ok"
+                )),
+                None => result.push(format!("{},{},{}", point, summary.as_csv(), "ok")),
+            }
+        }
+        let columns = RunSummary::csv_headers().split(',').count()
+            + statistics_headers
+                .map(|headers| headers.split(',').count())
+                .unwrap_or_default();
+        let empty_columns = ",".repeat(columns);
+        for failure in &self.failures {
+            result.push(format!(
+                "{}{},{}",
+                failure.point().map(|p| p.as_str()).unwrap_or_default(),
+                empty_columns,
+                failure.kind().status()
+            ));
         }
         result
     }
 
-    pub(crate) fn config(&self) -> String {
-        self.config.clone()
+    /// Read a [SeriesSummary] from CSV lines written by
+    /// [crate::benchmarks::Benchmarks::save_to_csv], with or without the headers and the
+    /// configuration, and with or without the status column of earlier versions
+    ///
+    /// CSV does not hold the messages of failures, they are left empty. The optional statistics
+    /// columns are recognized by the headers and are not read, they cannot be read from CSV
+    /// without headers.
+    ///
+    /// * `name` - the name of the series, the file name without the extension when saved by
+    ///   [crate::benchmarks::Benchmarks::save_to_csv]
+    /// * `csv` - the content of the CSV file
+    pub fn from_csv(name: &str, csv: &str) -> Result<SeriesSummary, Error> {
+        let columns = RunSummary::csv_headers().split(',').count();
+        let mut series_summary = SeriesSummary::new(name.to_string(), String::new());
+        let mut statistics_columns = 0;
+        for (i, line) in csv.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with(&format!("point,{}", RunSummary::csv_headers())) {
+                let headers = match line.split_once(",,configuration: ") {
+                    Some((headers, config)) => {
+                        series_summary.config = config.to_string();
+                        headers
+                    }
+                    None => line,
+                };
+                let headers: Vec<&str> = headers.split(',').collect();
+                let status_columns = usize::from(headers.last() == Some(&"status"));
+                statistics_columns = headers.len() - 1 - columns - status_columns;
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').collect();
+            let with_status = fields
+                .last()
+                .is_some_and(|status| status.trim().parse::<f64>().is_err());
+            let run_end = if with_status {
+                fields.len() - 1
+            } else {
+                fields.len()
+            }
+            .saturating_sub(statistics_columns);
+            if run_end <= columns {
+                return Err(anyhow!("line {}: Too few columns: {}", i + 1, line));
+            }
+            let point = fields[..run_end - columns].join(",");
+            let run_columns = &fields[run_end - columns..run_end];
+            match if with_status {
+                fields[fields.len() - 1]
+            } else {
+                "ok"
+            } {
+                "ok" => series_summary.add(
+                    point,
+                    RunSummary::from_csv(name.to_string(), run_columns)
+                        .with_context(|| anyhow!("line {}", i + 1))?,
+                ),
+                status => {
+                    let kind = FailureKind::from_status(status)
+                        .ok_or_else(|| anyhow!("line {}: Unknown status: {}", i + 1, status))?;
+                    let point = (!point.is_empty()).then_some(point);
+                    series_summary.add_failure(Failure::new(point, 0, kind, String::new()));
+                }
+            }
+        }
+        Ok(series_summary)
+    }
+
+    /// Read a [SeriesSummary] from a CSV file written by
+    /// [crate::benchmarks::Benchmarks::save_to_csv], see [Self::from_csv]
+    ///
+    /// The name of the series is the file name without the last extension, so that it may contain
+    /// dots.
+    pub fn from_csv_file(path: &Path) -> Result<SeriesSummary, Error> {
+        let name = path
+            .file_stem()
+            .ok_or_else(|| anyhow!("Not a file: {}", path.to_string_lossy()))?
+            .to_string_lossy()
+            .to_string();
+        let csv = fs::read_to_string(path)
+            .with_context(|| anyhow!("path: {}", path.to_string_lossy()))?;
+        SeriesSummary::from_csv(&name, &csv)
+            .with_context(|| anyhow!("path: {}", path.to_string_lossy()))
+    }
+
+    /// Get the name of the series
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Get the configuration of the series as a string
+    pub fn config(&self) -> &String {
+        &self.config
     }
 }
@@ -0,0 +1,288 @@
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+/// z value of the two-sided 95% confidence level
+const Z_95: f64 = 1.959964;
+
+/// Distribution free 95% confidence interval of the median based on order statistics
+///
+/// * `sorted` - samples sorted in ascending order
+pub(crate) fn median_confidence_interval(sorted: &[f64]) -> Option<(f64, f64)> {
+    let n = sorted.len();
+    if n < 2 {
+        None
+    } else {
+        let half_width = Z_95 * (n as f64).sqrt() / 2.0;
+        let lower = ((n as f64 / 2.0 - half_width).floor() as usize).min(n - 1);
+        let upper = ((n as f64 / 2.0 + half_width).ceil() as usize).min(n - 1);
+        Some((sorted[lower], sorted[upper]))
+    }
+}
+
+/// Width of the 95% confidence interval of the median in percents of the median
+///
+/// * `samples` - samples in arbitrary order
+pub(crate) fn median_precision(samples: &[f64]) -> Option<f64> {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let median = median(&sorted);
+    match median_confidence_interval(&sorted) {
+        Some((lower, upper)) if median > 0.0 => Some((upper - lower) / median * 100.0),
+        _ => None,
+    }
+}
+
+/// Median of samples sorted in ascending order
+pub(crate) fn median(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n == 0 {
+        f64::NAN
+    } else if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    }
+}
+
+/// Percentile of samples sorted in ascending order, linearly interpolated between the closest
+/// ranks
+///
+/// * `sorted` - samples sorted in ascending order
+/// * `p` - percentile between 0 and 100
+pub(crate) fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let n = sorted.len();
+    if n == 0 {
+        f64::NAN
+    } else {
+        let rank = p.clamp(0.0, 100.0) / 100.0 * (n - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+    }
+}
+
+/// Median of the absolute deviations of the samples from their median
+///
+/// * `sorted` - samples sorted in ascending order
+pub(crate) fn median_absolute_deviation(sorted: &[f64]) -> f64 {
+    let median = median(sorted);
+    let mut deviations: Vec<f64> = sorted.iter().map(|x| (x - median).abs()).collect();
+    deviations.sort_by(f64::total_cmp);
+    self::median(&deviations)
+}
+
+/// SplitMix64 pseudo random generator, good enough for resampling and reproducible across
+/// platforms
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    pub(crate) fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed index below `n`, ignoring the negligible modulo bias
+    pub(crate) fn index(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Bootstrap confidence intervals of the median and of the mean, computed with the percentile
+/// method
+///
+/// * `samples` - samples in arbitrary order, not empty
+/// * `confidence` - confidence level in percents
+/// * `resamples` - number of resamples
+/// * `seed` - seed of the pseudo random generator
+///
+/// Returns the intervals of the median and of the mean.
+pub(crate) fn bootstrap(
+    samples: &[f64],
+    confidence: f64,
+    resamples: usize,
+    seed: u64,
+) -> ((f64, f64), (f64, f64)) {
+    let n = samples.len();
+    let mut random = SplitMix64::new(seed);
+    let mut medians = Vec::with_capacity(resamples);
+    let mut means = Vec::with_capacity(resamples);
+    let mut resample = vec![0.0; n];
+    for _i in 0..resamples.max(1) {
+        for value in resample.iter_mut() {
+            *value = samples[random.index(n)];
+        }
+        resample.sort_by(f64::total_cmp);
+        medians.push(median(&resample));
+        means.push(resample.iter().sum::<f64>() / n as f64);
+    }
+    medians.sort_by(f64::total_cmp);
+    means.sort_by(f64::total_cmp);
+    let tail = (100.0 - confidence) / 2.0;
+    let interval = |sorted: &[f64]| (percentile(sorted, tail), percentile(sorted, 100.0 - tail));
+    (interval(&medians), interval(&means))
+}
+
+/// Welch's t-test of the difference between the means of two samples
+///
+/// Returns the two-sided p-value and Cohen's d of `a` relative to `b`, or `None` when either
+/// sample has less than two values or both have no variance.
+pub(crate) fn welch_t_test(a: &[f64], b: &[f64]) -> Option<(f64, f64)> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (mean_a, variance_a) = mean_variance(a);
+    let (mean_b, variance_b) = mean_variance(b);
+    let (se_a, se_b) = (variance_a / a.len() as f64, variance_b / b.len() as f64);
+    let se = (se_a + se_b).sqrt();
+    if se == 0.0 {
+        return None;
+    }
+    let t = (mean_a - mean_b) / se;
+    let df = (se_a + se_b).powi(2)
+        / (se_a.powi(2) / (a.len() - 1) as f64 + se_b.powi(2) / (b.len() - 1) as f64);
+    let p = 2.0 * (1.0 - StudentsT::new(0.0, 1.0, df).ok()?.cdf(t.abs()));
+    let effect_size = (mean_a - mean_b) / ((variance_a + variance_b) / 2.0).sqrt();
+    Some((p.clamp(0.0, 1.0), effect_size))
+}
+
+/// Mann-Whitney U test of the difference between the distributions of two samples, using the
+/// normal approximation with tie and continuity corrections
+///
+/// Returns the two-sided p-value and the rank-biserial correlation of `a` relative to `b`,
+/// positive when values of `a` tend to be greater, or `None` when either sample is empty or all
+/// values are equal.
+pub(crate) fn mann_whitney_u_test(a: &[f64], b: &[f64]) -> Option<(f64, f64)> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let mut values: Vec<(f64, bool)> = a
+        .iter()
+        .map(|&x| (x, true))
+        .chain(b.iter().map(|&x| (x, false)))
+        .collect();
+    values.sort_by(|x, y| x.0.total_cmp(&y.0));
+    let n = values.len() as f64;
+    let (mut rank_sum_a, mut ties) = (0.0, 0.0);
+    let mut i = 0;
+    while i < values.len() {
+        let j = i + values[i..]
+            .iter()
+            .take_while(|x| x.0 == values[i].0)
+            .count();
+        let rank = (i + 1 + j) as f64 / 2.0;
+        rank_sum_a += rank * values[i..j].iter().filter(|x| x.1).count() as f64;
+        let t = (j - i) as f64;
+        ties += t.powi(3) - t;
+        i = j;
+    }
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let u = rank_sum_a - n_a * (n_a + 1.0) / 2.0;
+    let sigma = (n_a * n_b / 12.0 * ((n + 1.0) - ties / (n * (n - 1.0)))).sqrt();
+    if sigma.is_nan() || sigma == 0.0 {
+        return None;
+    }
+    let z = ((u - n_a * n_b / 2.0).abs() - 0.5).max(0.0) / sigma;
+    let p = 2.0 * (1.0 - Normal::new(0.0, 1.0).ok()?.cdf(z));
+    Some((p.clamp(0.0, 1.0), 2.0 * u / (n_a * n_b) - 1.0))
+}
+
+/// Mean and unbiased variance of a sample with at least two values
+fn mean_variance(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_confidence_interval() {
+        let sorted: Vec<f64> = (1..=100).map(|x| x as f64).collect();
+        let (lower, upper) = median_confidence_interval(&sorted).unwrap();
+        assert!((39.0..50.0).contains(&lower));
+        assert!((52.0..=62.0).contains(&upper));
+        assert!(median_confidence_interval(&[1.0]).is_none());
+    }
+
+    #[test]
+    fn test_median_precision() {
+        let constant = vec![10.0; 20];
+        assert_eq!(median_precision(&constant), Some(0.0));
+        let spread: Vec<f64> = (1..=20).map(|x| x as f64).collect();
+        assert!(median_precision(&spread).unwrap() > 10.0);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let sorted: Vec<f64> = (1..=5).map(|x| x as f64).collect();
+        assert_eq!(percentile(&sorted, 0.0), 1.0);
+        assert_eq!(percentile(&sorted, 50.0), 3.0);
+        assert_eq!(percentile(&sorted, 100.0), 5.0);
+        assert_eq!(percentile(&sorted, 90.0), 4.6);
+        assert_eq!(percentile(&[7.0], 25.0), 7.0);
+        assert!(percentile(&[], 25.0).is_nan());
+    }
+
+    #[test]
+    fn test_median_absolute_deviation() {
+        assert_eq!(
+            median_absolute_deviation(&[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]),
+            1.0
+        );
+        assert_eq!(median_absolute_deviation(&[5.0, 5.0, 5.0]), 0.0);
+    }
+
+    #[test]
+    fn test_split_mix_64() {
+        let mut random = SplitMix64::new(1234567);
+        assert_eq!(random.next(), 6457827717110365317);
+        assert_eq!(random.next(), 3203168211198807973);
+        assert!((0..1000).all(|_| random.index(7) < 7));
+    }
+
+    #[test]
+    fn test_bootstrap() {
+        let samples: Vec<f64> = (1..=101).map(|x| x as f64).collect();
+        let (median_interval, mean_interval) = bootstrap(&samples, 95.0, 1000, 0);
+        assert!(median_interval.0 < 51.0 && 51.0 < median_interval.1);
+        assert!(mean_interval.0 < 51.0 && 51.0 < mean_interval.1);
+        assert!(mean_interval.1 - mean_interval.0 < 20.0);
+        assert_eq!(
+            bootstrap(&samples, 95.0, 1000, 0),
+            (median_interval, mean_interval)
+        );
+        assert_eq!(bootstrap(&[5.0], 90.0, 10, 0), ((5.0, 5.0), (5.0, 5.0)));
+    }
+
+    #[test]
+    fn test_welch_t_test() {
+        let (p, effect_size) =
+            welch_t_test(&[1.0, 2.0, 3.0, 4.0, 5.0], &[3.0, 4.0, 5.0, 6.0, 7.0]).unwrap();
+        assert!((p - 0.0805).abs() < 1e-3);
+        assert!((effect_size + 1.2649).abs() < 1e-3);
+        assert!(welch_t_test(&[1.0], &[1.0, 2.0]).is_none());
+        assert!(welch_t_test(&[1.0, 1.0], &[1.0, 1.0]).is_none());
+    }
+
+    #[test]
+    fn test_mann_whitney_u_test() {
+        let (p, effect_size) = mann_whitney_u_test(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]).unwrap();
+        assert!((p - 0.0809).abs() < 1e-3);
+        assert_eq!(effect_size, -1.0);
+        let (p, effect_size) = mann_whitney_u_test(&[1.0, 2.0, 2.0], &[2.0, 1.0, 2.0]).unwrap();
+        assert_eq!(p, 1.0);
+        assert_eq!(effect_size, 0.0);
+        assert!(mann_whitney_u_test(&[3.0, 3.0], &[3.0]).is_none());
+        assert!(mann_whitney_u_test(&[], &[3.0]).is_none());
+    }
+}
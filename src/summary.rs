@@ -1,7 +1,13 @@
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 
+use anyhow::{anyhow, Context, Error};
 use serde::{Deserialize, Serialize};
 
+use crate::analysis_result::AnalysisResult;
+use crate::comparison::{self, ComparisonOptions};
+use crate::filter::Filter;
 use crate::series_summary::SeriesSummary;
 
 /// Benchmark summary
@@ -9,18 +15,113 @@ use crate::series_summary::SeriesSummary;
 pub struct Summary {
     name: String,
     created_at: String,
+    #[serde(default = "Summary::default_workers")]
+    workers: usize,
+    #[serde(default)]
+    filter: Filter,
+    #[serde(default)]
+    skipped: Vec<String>,
     series: HashMap<String, SeriesSummary>,
 }
 
 impl Summary {
-    pub(crate) fn new(name: String) -> Summary {
+    pub(crate) fn new(name: String, workers: usize) -> Summary {
         Summary {
             name,
             created_at: chrono::Utc::now().naive_utc().to_string(),
+            workers,
+            filter: Filter::new(),
+            skipped: vec![],
             series: HashMap::new(),
         }
     }
 
+    /// Deserialize a [Summary] from a JSON string
+    ///
+    /// The error names the field that failed to deserialize.
+    pub fn from_json(json: &str) -> Result<Summary, Error> {
+        let deserializer = &mut serde_json::Deserializer::from_str(json);
+        serde_path_to_error::deserialize(deserializer).map_err(|e| {
+            let path = e.path().to_string();
+            anyhow!(e.into_inner()).context(format!("field: {}", path))
+        })
+    }
+
+    /// Load a [Summary] from a JSON file
+    ///
+    /// The error names the file and the field that failed to deserialize.
+    pub fn from_json_file(path: &Path) -> Result<Summary, Error> {
+        let json = fs::read_to_string(path)
+            .with_context(|| anyhow!("path: {}", path.to_string_lossy()))?;
+        Summary::from_json(&json).with_context(|| anyhow!("path: {}", path.to_string_lossy()))
+    }
+
+    /// Load the [Summary] of the suite `name` saved to `dir` by
+    /// [crate::benchmarks::Benchmarks::save_to_json]
+    pub fn load(dir: &Path, name: &str) -> Result<Summary, Error> {
+        Summary::from_json_file(&dir.join(format!("{name}.json")))
+    }
+
+    /// Load all summaries saved to `dir` by [crate::benchmarks::Benchmarks::save_to_json], keyed
+    /// by the suite name
+    ///
+    /// Files without the `json` extension are ignored. Two files with the same suite name are an
+    /// error that names both files.
+    pub fn load_dir(dir: &Path) -> Result<HashMap<String, Summary>, Error> {
+        let mut summaries = HashMap::new();
+        let mut paths: HashMap<String, PathBuf> = HashMap::new();
+        for entry in
+            fs::read_dir(dir).with_context(|| anyhow!("path: {}", dir.to_string_lossy()))?
+        {
+            let path = entry?.path();
+            if path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+            {
+                let summary = Summary::from_json_file(&path)?;
+                if let Some(previous) = paths.insert(summary.name().clone(), path.clone()) {
+                    return Err(anyhow!(
+                        "Suite {} is saved in both {} and {}",
+                        summary.name(),
+                        previous.to_string_lossy(),
+                        path.to_string_lossy()
+                    ));
+                }
+                summaries.insert(summary.name().clone(), summary);
+            }
+        }
+        Ok(summaries)
+    }
+
+    /// Read a [Summary] from the CSV files written to `dir` by
+    /// [crate::benchmarks::Benchmarks::save_to_csv]
+    ///
+    /// Each file with the `csv` extension is read as a series with [SeriesSummary::from_csv_file].
+    /// CSV files do not hold the suite name, the creation time or the number of workers, the
+    /// summary is named `name`, created at the latest modification time of the files and
+    /// assumed to be run serially.
+    pub fn load_csv_dir(dir: &Path, name: &str) -> Result<Summary, Error> {
+        let mut summary = Summary::new(name.to_string(), 1);
+        let mut modified = None;
+        for entry in
+            fs::read_dir(dir).with_context(|| anyhow!("path: {}", dir.to_string_lossy()))?
+        {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|extension| extension == "csv") {
+                modified = modified.max(path.metadata()?.modified().ok());
+                let series_summary = SeriesSummary::from_csv_file(&path)?;
+                summary.add(series_summary.name().clone(), series_summary);
+            }
+        }
+        if let Some(modified) = modified {
+            summary.created_at = chrono::DateTime::<chrono::Utc>::from(modified)
+                .naive_utc()
+                .to_string();
+        }
+        Ok(summary)
+    }
+
     /// Get benchmark suite name
     pub fn name(&self) -> &String {
         &self.name
@@ -35,8 +136,50 @@ impl Summary {
         &self.created_at
     }
 
+    /// Get the number of benchmark series that were run concurrently, 1 for serial runs
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    fn default_workers() -> usize {
+        1
+    }
+
+    pub(crate) fn set_filter(&mut self, filter: Filter, skipped: Vec<String>) {
+        self.filter = filter;
+        self.skipped = skipped;
+    }
+
+    /// Get the [Filter] the benchmarks were run with
+    pub fn filter(&self) -> &Filter {
+        &self.filter
+    }
+
+    /// Get names of the series that were not selected by the [Filter] and were not run
+    pub fn skipped(&self) -> &Vec<String> {
+        &self.skipped
+    }
+
     /// Get reference to [SeriesSummary] by name
     pub fn series(&self) -> &HashMap<String, SeriesSummary> {
         &self.series
     }
+
+    /// Check if any series recorded a failure
+    pub fn has_failures(&self) -> bool {
+        self.series
+            .values()
+            .any(|series_summary| series_summary.has_failures())
+    }
+
+    /// Compare this summary against the summary of a previous run
+    ///
+    /// Same as [crate::comparison::compare_summaries] with the default
+    /// [ComparisonOptions] and the provided threshold.
+    ///
+    /// * `previous` - the [Summary] of the previous run
+    /// * `threshold` - threshold used to determine equality.
+    pub fn analyze(&self, previous: &Summary, threshold: f64) -> Result<AnalysisResult, Error> {
+        comparison::compare_summaries(self, previous, &ComparisonOptions::new(threshold))
+    }
 }
@@ -0,0 +1,400 @@
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::benchmark::{call_by_reference, call_by_value, call_disposable, call_staged};
+use crate::stopwatch::StopWatch;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Thread safety of the benchmark functions and hooks of a [crate::benchmarks::Benchmarks] suite
+///
+/// The benchmark functions and hooks of a [Local] suite run on the thread that runs the suite
+/// and need not be `Send`. A [Threaded] suite requires them to be `Send`, so that its series can
+/// be run concurrently with [crate::benchmarks::Benchmarks::run_parallel] and an invocation that
+/// exceeds a timeout can be abandoned, see [Dispatch].
+pub trait Threading: private::Sealed + 'static {
+    /// Benchmark function, invoked with the configuration, the workload point, the number of
+    /// invocations in a batch and a callback that marks the end of the measured invocation before
+    /// its input is released, see [crate::benchmarks::Benchmarks::add_with_setup_and_teardown]
+    type Invoke<C, W, E>: ?Sized
+        + FnMut(&mut StopWatch, &C, &W, usize, &mut dyn FnMut()) -> Result<(), E>;
+    /// Series setup or teardown
+    type SeriesHook<C, E>: ?Sized + FnMut(&C) -> Result<(), E>;
+    /// Workload point teardown
+    type PointHook<C, W, E>: ?Sized + FnMut(&C, &W) -> Result<(), E>;
+}
+
+/// Suite created with [crate::benchmarks::Benchmarks::new], see [Threading]
+pub struct Local;
+
+/// Suite created with [crate::benchmarks::Benchmarks::new_threaded], see [Threading]
+pub struct Threaded;
+
+impl private::Sealed for Local {}
+
+impl private::Sealed for Threaded {}
+
+impl Threading for Local {
+    type Invoke<C, W, E> =
+        dyn FnMut(&mut StopWatch, &C, &W, usize, &mut dyn FnMut()) -> Result<(), E>;
+    type SeriesHook<C, E> = dyn FnMut(&C) -> Result<(), E>;
+    type PointHook<C, W, E> = dyn FnMut(&C, &W) -> Result<(), E>;
+}
+
+impl Threading for Threaded {
+    type Invoke<C, W, E> =
+        dyn FnMut(&mut StopWatch, &C, &W, usize, &mut dyn FnMut()) -> Result<(), E> + Send;
+    type SeriesHook<C, E> = dyn FnMut(&C) -> Result<(), E> + Send;
+    type PointHook<C, W, E> = dyn FnMut(&C, &W) -> Result<(), E> + Send;
+}
+
+/// Closures that can be added to a suite with the [Threading] `T`
+///
+/// Implemented by all `'static` closures for [Local] suites and by `Send + 'static` closures for
+/// [Threaded] suites. The setup and the benchmark function of
+/// [crate::benchmarks::Benchmarks::add_with_setup] are required to be [Movable] as a pair, and
+/// together with the teardown of [crate::benchmarks::Benchmarks::add_with_setup_and_teardown]
+/// as a triple.
+pub trait Movable<T: Threading>: Sized {
+    #[doc(hidden)]
+    fn into_by_value<C: Clone, W: Clone, E>(self) -> Box<T::Invoke<C, W, E>>
+    where
+        Self: FnMut(&mut StopWatch, C, W) -> Result<(), E>;
+
+    #[doc(hidden)]
+    fn into_by_reference<C, W, E>(self) -> Box<T::Invoke<C, W, E>>
+    where
+        Self: FnMut(&mut StopWatch, &C, &W) -> Result<(), E>;
+
+    #[doc(hidden)]
+    fn into_staged<C, W, E, I>(self) -> Box<T::Invoke<C, W, E>>
+    where
+        Self: Staged<C, W, E, I>;
+
+    #[doc(hidden)]
+    fn into_series_hook<C, E>(self) -> Box<T::SeriesHook<C, E>>
+    where
+        Self: FnMut(&C) -> Result<(), E>;
+
+    #[doc(hidden)]
+    fn into_point_hook<C, W, E>(self) -> Box<T::PointHook<C, W, E>>
+    where
+        Self: FnMut(&C, &W) -> Result<(), E>;
+}
+
+/// Setup and benchmark function of [crate::benchmarks::Benchmarks::add_with_setup], or setup,
+/// benchmark function and teardown of [crate::benchmarks::Benchmarks::add_with_setup_and_teardown]
+#[doc(hidden)]
+pub trait Staged<C, W, E, I> {
+    fn call(
+        &mut self,
+        stop_watch: &mut StopWatch,
+        config: &C,
+        w: &W,
+        batch: usize,
+        called: &mut dyn FnMut(),
+    ) -> Result<(), E>;
+}
+
+impl<C, W, E, I, S, F> Staged<C, W, E, I> for (S, F)
+where
+    C: Clone,
+    S: FnMut(&C, &W) -> Result<I, E>,
+    F: FnMut(&mut StopWatch, C, I) -> Result<(), E>,
+{
+    fn call(
+        &mut self,
+        stop_watch: &mut StopWatch,
+        config: &C,
+        w: &W,
+        batch: usize,
+        _called: &mut dyn FnMut(),
+    ) -> Result<(), E> {
+        let (setup, f) = self;
+        call_staged(setup, f, stop_watch, config, w, batch)
+    }
+}
+
+impl<C, W, E, I, S, F, D> Staged<C, W, E, I> for (S, F, D)
+where
+    C: Clone,
+    S: FnMut(&C, &W) -> Result<I, E>,
+    F: FnMut(&mut StopWatch, C, &mut I) -> Result<(), E>,
+    D: FnMut(&C, &W, I) -> Result<(), E>,
+{
+    fn call(
+        &mut self,
+        stop_watch: &mut StopWatch,
+        config: &C,
+        w: &W,
+        batch: usize,
+        called: &mut dyn FnMut(),
+    ) -> Result<(), E> {
+        let (setup, f, teardown) = self;
+        call_disposable(setup, f, teardown, stop_watch, config, w, batch, called)
+    }
+}
+
+macro_rules! impl_movable {
+    ($threading:ty $(, $bound:path)?) => {
+        impl<F: 'static $(+ $bound)?> Movable<$threading> for F {
+            fn into_by_value<C: Clone, W: Clone, E>(
+                mut self,
+            ) -> Box<<$threading as Threading>::Invoke<C, W, E>>
+            where
+                Self: FnMut(&mut StopWatch, C, W) -> Result<(), E>,
+            {
+                Box::new(
+                    move |stop_watch: &mut StopWatch,
+                          config: &C,
+                          w: &W,
+                          batch,
+                          _called: &mut dyn FnMut()| {
+                        call_by_value(&mut self, stop_watch, config, w, batch)
+                    },
+                )
+            }
+
+            fn into_by_reference<C, W, E>(
+                mut self,
+            ) -> Box<<$threading as Threading>::Invoke<C, W, E>>
+            where
+                Self: FnMut(&mut StopWatch, &C, &W) -> Result<(), E>,
+            {
+                Box::new(
+                    move |stop_watch: &mut StopWatch,
+                          config: &C,
+                          w: &W,
+                          batch,
+                          _called: &mut dyn FnMut()| {
+                        call_by_reference(&mut self, stop_watch, config, w, batch)
+                    },
+                )
+            }
+
+            fn into_staged<C, W, E, I>(
+                mut self,
+            ) -> Box<<$threading as Threading>::Invoke<C, W, E>>
+            where
+                Self: Staged<C, W, E, I>,
+            {
+                Box::new(
+                    move |stop_watch: &mut StopWatch,
+                          config: &C,
+                          w: &W,
+                          batch,
+                          called: &mut dyn FnMut()| {
+                        self.call(stop_watch, config, w, batch, called)
+                    },
+                )
+            }
+
+            fn into_series_hook<C, E>(self) -> Box<<$threading as Threading>::SeriesHook<C, E>>
+            where
+                Self: FnMut(&C) -> Result<(), E>,
+            {
+                Box::new(self)
+            }
+
+            fn into_point_hook<C, W, E>(
+                self,
+            ) -> Box<<$threading as Threading>::PointHook<C, W, E>>
+            where
+                Self: FnMut(&C, &W) -> Result<(), E>,
+            {
+                Box::new(self)
+            }
+        }
+    };
+}
+
+impl_movable!(Local);
+impl_movable!(Threaded, Send);
+
+/// Invocation of the benchmark functions of a suite with the [Threading] `T`
+///
+/// When an iteration or a point timeout is set, the benchmark function of a [Threaded] suite is
+/// moved to a worker thread for each workload point, so that an invocation that does not return
+/// in time is abandoned and recorded as a timeout. The release of the input of an invocation
+/// that returned is waited for without a limit. The benchmark functions of a [Local] suite are
+/// always invoked on the thread that runs the suite.
+pub trait Dispatch<C, W, E>: Threading {
+    /// Move `f` to a worker thread that invokes it with clones of `config` and `w`, see [Worker]
+    ///
+    /// Returns `f` back when the benchmark functions are invoked on the thread that runs the
+    /// suite.
+    #[doc(hidden)]
+    #[allow(clippy::type_complexity)]
+    fn watch(
+        f: Box<Self::Invoke<C, W, E>>,
+        config: &C,
+        w: &W,
+    ) -> Result<Worker<E, Self::Invoke<C, W, E>>, Box<Self::Invoke<C, W, E>>>;
+
+    /// Same as [Self::watch] for a benchmark function added with
+    /// [crate::benchmarks::Benchmarks::add]
+    #[doc(hidden)]
+    fn watch_function(
+        f: fn(&mut StopWatch, C, W) -> Result<(), E>,
+        config: &C,
+        w: &W,
+    ) -> Option<Worker<E, Self::Invoke<C, W, E>>>;
+}
+
+impl<C: Clone, W: Clone, E> Dispatch<C, W, E> for Local {
+    fn watch(
+        f: Box<Self::Invoke<C, W, E>>,
+        _config: &C,
+        _w: &W,
+    ) -> Result<Worker<E, Self::Invoke<C, W, E>>, Box<Self::Invoke<C, W, E>>> {
+        Err(f)
+    }
+
+    fn watch_function(
+        _f: fn(&mut StopWatch, C, W) -> Result<(), E>,
+        _config: &C,
+        _w: &W,
+    ) -> Option<Worker<E, Self::Invoke<C, W, E>>> {
+        None
+    }
+}
+
+impl<C, W, E> Dispatch<C, W, E> for Threaded
+where
+    C: Clone + Send + 'static,
+    W: Clone + Send + 'static,
+    E: Send + 'static,
+{
+    fn watch(
+        mut invoke: Box<Self::Invoke<C, W, E>>,
+        config: &C,
+        w: &W,
+    ) -> Result<Worker<E, Self::Invoke<C, W, E>>, Box<Self::Invoke<C, W, E>>> {
+        let (config, w) = (config.clone(), w.clone());
+        let (requests, received) = mpsc::channel::<(StopWatch, usize)>();
+        let (sender, responses) = mpsc::channel();
+        thread::spawn(move || {
+            for (mut stop_watch, batch) in received {
+                let result = catch_unwind(AssertUnwindSafe(|| {
+                    invoke(&mut stop_watch, &config, &w, batch, &mut || {
+                        let _ = sender.send(Response::Called);
+                    })
+                }));
+                // the receiver is gone when the worker was abandoned and dropped
+                let _ = sender.send(Response::Done(stop_watch, result));
+            }
+            let _ = sender.send(Response::Returned(invoke));
+        });
+        Ok(Worker {
+            requests: Some(requests),
+            responses,
+        })
+    }
+
+    fn watch_function(
+        mut f: fn(&mut StopWatch, C, W) -> Result<(), E>,
+        config: &C,
+        w: &W,
+    ) -> Option<Worker<E, Self::Invoke<C, W, E>>> {
+        let invoke: Box<Self::Invoke<C, W, E>> = Box::new(
+            move |stop_watch: &mut StopWatch,
+                  config: &C,
+                  w: &W,
+                  batch,
+                  _called: &mut dyn FnMut()| {
+                call_by_value(&mut f, stop_watch, config, w, batch)
+            },
+        );
+        Self::watch(invoke, config, w).ok()
+    }
+}
+
+enum Response<E, F: ?Sized> {
+    /// The measured invocation returned and its input is being released
+    Called,
+    /// The invocations of a request returned
+    Done(StopWatch, thread::Result<Result<(), E>>),
+    /// The worker thread stopped and gives back the benchmark function
+    Returned(Box<F>),
+}
+
+/// Worker thread that invokes the benchmark function `F` at a workload point, see
+/// [Dispatch::watch]
+///
+/// An invocation that does not return in time is abandoned. The worker thread keeps running it
+/// and gives back the benchmark function once it returns, see [Self::reclaim].
+#[doc(hidden)]
+pub struct Worker<E, F: ?Sized> {
+    requests: Option<mpsc::Sender<(StopWatch, usize)>>,
+    responses: mpsc::Receiver<Response<E, F>>,
+}
+
+impl<E, F: ?Sized> Worker<E, F> {
+    /// Invoke the benchmark function `batch` times and wait at most `timeout` for it to return
+    ///
+    /// The release of the input of an invocation is waited for without a limit, and its duration
+    /// is returned with the result. Returns `None` when the invocation did not return in time,
+    /// the worker is then abandoned.
+    pub(crate) fn call(
+        &mut self,
+        stop_watch: &mut StopWatch,
+        batch: usize,
+        timeout: Option<Duration>,
+    ) -> Option<(Result<(), E>, Duration)> {
+        let requests = self.requests.as_ref()?;
+        requests.send((std::mem::take(stop_watch), batch)).ok()?;
+        let mut response = match timeout {
+            Some(timeout) => self.responses.recv_timeout(timeout).ok(),
+            None => self.responses.recv().ok(),
+        };
+        let mut called = None;
+        if let Some(Response::Called) = response {
+            called = Some(Instant::now());
+            response = self.responses.recv().ok();
+        }
+        match response {
+            Some(Response::Done(watched, result)) => {
+                *stop_watch = watched;
+                Some((
+                    result.unwrap_or_else(|payload| resume_unwind(payload)),
+                    called.map_or(Duration::ZERO, |called| called.elapsed()),
+                ))
+            }
+            _ => {
+                self.requests = None;
+                None
+            }
+        }
+    }
+
+    /// Check if an invocation did not return in time
+    pub(crate) fn abandoned(&self) -> bool {
+        self.requests.is_none()
+    }
+
+    /// Stop the worker thread and wait at most `timeout` for it to give back the benchmark
+    /// function
+    ///
+    /// Returns `None` when an abandoned invocation did not return in time.
+    pub(crate) fn reclaim(&mut self, timeout: Option<Duration>) -> Option<Box<F>> {
+        self.requests = None;
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let response = match deadline {
+                Some(deadline) => self
+                    .responses
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    .ok(),
+                None => self.responses.recv().ok(),
+            };
+            // results of abandoned invocations are discarded
+            if let Response::Returned(f) = response? {
+                return Some(f);
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::filter::NamePattern;
+
+/// Changes of the compared metric within which two runs of a workload point are considered equal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    regression: f64,
+    improvement: f64,
+    floor_nanos: u64,
+}
+
+impl Threshold {
+    /// Create a [Threshold]
+    ///
+    /// * `regression` - change of the compared metric in percents considered equal when it is
+    ///   worse in the current run, a higher duration or a lower
+    ///   [crate::metric::Metric::Throughput]
+    /// * `improvement` - change of the compared metric in percents considered equal when it is
+    ///   better in the current run
+    /// * `floor_nanos` - absolute change of the duration of an invocation in nanoseconds
+    ///   considered equal regardless of the percents, so that points that run for nanoseconds do
+    ///   not trip on relative noise. The throughput is compared by the duration of an operation,
+    ///   its reciprocal.
+    pub fn new(regression: f64, improvement: f64, floor_nanos: u64) -> Threshold {
+        Threshold {
+            regression,
+            improvement,
+            floor_nanos,
+        }
+    }
+
+    /// Create a [Threshold] of `percent` for both regressions and improvements, without a floor
+    pub fn percent(percent: f64) -> Threshold {
+        Threshold::new(percent, percent, 0)
+    }
+
+    /// Get the regression threshold in percents
+    pub fn regression(&self) -> f64 {
+        self.regression
+    }
+
+    /// Get the improvement threshold in percents
+    pub fn improvement(&self) -> f64 {
+        self.improvement
+    }
+
+    /// Get the absolute threshold in nanoseconds
+    pub fn floor_nanos(&self) -> u64 {
+        self.floor_nanos
+    }
+
+    /// Check if a change is within the threshold
+    ///
+    /// * `difference_nanos` - absolute difference of the durations in nanoseconds
+    /// * `regression` - change in percents, positive when the current run is worse
+    pub(crate) fn is_within(&self, difference_nanos: f64, regression: f64) -> bool {
+        difference_nanos <= self.floor_nanos as f64
+            || (regression >= 0.0 && regression <= self.regression.abs())
+            || (regression < 0.0 && -regression <= self.improvement.abs())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ThresholdRule {
+    series: NamePattern,
+    point: Option<String>,
+    threshold: Threshold,
+}
+
+/// Thresholds of individual benchmark series and workload points
+///
+/// Workload points that no rule applies to are compared with the threshold of the
+/// [crate::comparison::ComparisonOptions]. Rules for a workload point take precedence over rules
+/// for a whole series, and later rules take precedence over earlier ones.
+///
+/// ```
+/// use benchmark_rs::filter::NamePattern;
+/// use benchmark_rs::threshold::{Threshold, ThresholdPolicy};
+///
+/// let mut threshold_policy = ThresholdPolicy::new();
+/// // I/O bound series are noisy
+/// threshold_policy.add_series(NamePattern::glob("io *"), Threshold::percent(20.0));
+/// // the smallest point runs for microseconds, ignore changes below 500 ns
+/// threshold_policy.add_point(NamePattern::glob("*"), "1", Threshold::new(5.0, 5.0, 500));
+/// assert_eq!(threshold_policy.threshold("io read", "10"), Some(&Threshold::percent(20.0)));
+/// assert_eq!(threshold_policy.threshold("io read", "1").unwrap().floor_nanos(), 500);
+/// assert_eq!(threshold_policy.threshold("sum", "10"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThresholdPolicy {
+    rules: Vec<ThresholdRule>,
+}
+
+impl ThresholdPolicy {
+    /// Create an empty [ThresholdPolicy]
+    pub fn new() -> ThresholdPolicy {
+        ThresholdPolicy::default()
+    }
+
+    /// Apply `threshold` to all workload points of the series with names that match `series`
+    pub fn add_series(&mut self, series: NamePattern, threshold: Threshold) {
+        self.add(series, None, threshold)
+    }
+
+    /// Apply `threshold` to the workload point `point` of the series with names that match
+    /// `series`
+    pub fn add_point(&mut self, series: NamePattern, point: &str, threshold: Threshold) {
+        self.add(series, Some(point.to_string()), threshold)
+    }
+
+    fn add(&mut self, series: NamePattern, point: Option<String>, threshold: Threshold) {
+        self.rules.push(ThresholdRule {
+            series,
+            point,
+            threshold,
+        });
+    }
+
+    /// Get the threshold of the workload point `point` of the series `series`, if any rule
+    /// applies to it
+    pub fn threshold(&self, series: &str, point: &str) -> Option<&Threshold> {
+        let matching = |for_point: bool| {
+            self.rules
+                .iter()
+                .rev()
+                .filter(move |rule| rule.point.is_some() == for_point)
+                .find(|rule| {
+                    let point_matches = match &rule.point {
+                        Some(p) => p == point,
+                        None => true,
+                    };
+                    point_matches && rule.series.matches(series)
+                })
+                .map(|rule| &rule.threshold)
+        };
+        matching(true).or_else(|| matching(false))
+    }
+}
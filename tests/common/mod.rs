@@ -0,0 +1,97 @@
+//! Fixtures shared by the integration tests
+#![allow(dead_code)]
+
+use std::thread;
+use std::time::Duration;
+
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::run_summary::RunSummary;
+use benchmark_rs::stopwatch::StopWatch;
+use serde_json::{json, Value};
+
+/// Sleep for `work` microseconds
+pub fn sleep(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    thread::sleep(Duration::from_micros(work));
+    Ok(())
+}
+
+/// Suite with a single `sleep` series sleeping 100 and 200 microseconds
+pub fn sleep_benchmarks(
+    repeat: usize,
+    ramp_up: usize,
+) -> Result<Benchmarks<&'static str, u64, anyhow::Error>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("sleep", sleep, "sleep", vec![100, 200], repeat, ramp_up)?;
+    Ok(benchmarks)
+}
+
+/// Build a [RunSummary] with chosen statistics, as if it was loaded from a saved summary
+pub struct RunSummaryBuilder {
+    run: Value,
+}
+
+impl RunSummaryBuilder {
+    /// Run of the series `name` with all durations zero
+    pub fn new(name: &str) -> RunSummaryBuilder {
+        RunSummaryBuilder {
+            run: json!({
+                "name": name,
+                "ramp_up": 0,
+                "repeat": 1,
+                "min_nanos": 0,
+                "min_sec": 0.0,
+                "min_str": "",
+                "max_nanos": 0,
+                "max_sec": 0.0,
+                "max_str": "",
+                "median_nanos": 0,
+                "median_sec": 0.0,
+                "median_str": "",
+                "std_dev": null,
+                "std_dev_sec": null,
+                "std_dev_str": "null",
+            }),
+        }
+    }
+
+    /// Set the minimum, the maximum and the median to `nanos`
+    pub fn median(mut self, nanos: u64) -> RunSummaryBuilder {
+        for field in ["min_nanos", "max_nanos", "median_nanos"] {
+            self.run[field] = json!(nanos);
+        }
+        self
+    }
+
+    /// Retain `samples` and set the repeat, the minimum, the maximum and the median from them
+    pub fn samples(mut self, samples: &[f64]) -> RunSummaryBuilder {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        self.run["repeat"] = json!(samples.len());
+        self.run["min_nanos"] = json!(sorted[0] as u64);
+        self.run["max_nanos"] = json!(sorted[sorted.len() - 1] as u64);
+        self.run["median_nanos"] = json!(sorted[sorted.len() / 2] as u64);
+        self.run["raw_samples"] = samples
+            .iter()
+            .enumerate()
+            .map(|(iteration, nanos)| json!({"iteration": iteration, "nanos": nanos}))
+            .collect();
+        self
+    }
+
+    /// Set the 95% confidence interval of the median
+    pub fn median_ci(mut self, lower: u64, upper: u64) -> RunSummaryBuilder {
+        self.run["median_ci"] =
+            json!({"confidence": 95.0, "lower_nanos": lower, "upper_nanos": upper});
+        self
+    }
+
+    /// Set any other field of the saved [RunSummary]
+    pub fn set(mut self, field: &str, value: Value) -> RunSummaryBuilder {
+        self.run[field] = value;
+        self
+    }
+
+    pub fn build(self) -> RunSummary {
+        serde_json::from_value(self.run).expect("invalid run summary")
+    }
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::{compare_runs, ComparisonOptions};
+use benchmark_rs::run_summary::RunSummary;
+use benchmark_rs::stopwatch::StopWatch;
+use common::RunSummaryBuilder;
+use serde_json::json;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn test_batching() -> Result<(), anyhow::Error> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let captured_calls = calls.clone();
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_closure(
+        "batched",
+        move |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            captured_calls.fetch_add(1, Ordering::Relaxed);
+            let _hash = work.wrapping_mul(0x9E3779B97F4A7C15);
+            Ok::<(), anyhow::Error>(())
+        },
+        "no configuration",
+        vec![1],
+        5,
+        0,
+    )?;
+    benchmarks.set_batching("batched", Duration::from_millis(1))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let run_summary = &summary.series()["batched"].runs()[0].1;
+    assert!(run_summary.batch() > 1);
+    assert_eq!(run_summary.repeat(), 5);
+    assert!(calls.load(Ordering::Relaxed) > 5 * run_summary.batch());
+    assert!(run_summary.median_nanos() < Duration::from_millis(1).as_nanos() as u64);
+    Ok(())
+}
+
+/// Batched run with the median of a single invocation at `nanos`
+fn batched_run(nanos: f64) -> RunSummary {
+    RunSummaryBuilder::new("batched")
+        .median(nanos.round() as u64)
+        .set("batch", json!(1000))
+        .set("min_sec", json!(nanos / 1e9))
+        .set("median_sec", json!(nanos / 1e9))
+        .build()
+}
+
+#[test]
+fn test_batching_fractional_nanos() -> Result<(), anyhow::Error> {
+    let options = ComparisonOptions::new(5.0);
+    let comparison = compare_runs("1", &batched_run(0.6), &batched_run(1.9), &options)?;
+    assert!(matches!(comparison, BenchmarkComparison::Less { .. }));
+    assert!((comparison.change() + 68.42).abs() < 0.01);
+
+    // a zero duration in the previous run cannot be compared against
+    assert!(compare_runs("1", &batched_run(0.6), &batched_run(0.0), &options).is_err());
+    assert!(matches!(
+        compare_runs("1", &batched_run(0.0), &batched_run(0.0), &options)?,
+        BenchmarkComparison::Equal { change, .. } if change == 0.0
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_batching_teardown() -> Result<(), anyhow::Error> {
+    let setups = Arc::new(AtomicUsize::new(0));
+    let teardowns = Arc::new(AtomicUsize::new(0));
+    let (captured_setups, captured_teardowns) = (setups.clone(), teardowns.clone());
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_with_setup(
+        "batched",
+        move |_config: &&str, work: &u64| {
+            captured_setups.fetch_add(1, Ordering::Relaxed);
+            Ok(vec![*work; 16])
+        },
+        |_stop_watch: &mut StopWatch, _config: &str, input: Vec<u64>| {
+            let _sum: u64 = input.iter().sum();
+            Ok::<(), anyhow::Error>(())
+        },
+        "no configuration",
+        vec![1],
+        3,
+        1,
+    )?;
+    benchmarks.set_point_teardown("batched", move |_config: &&str, _work: &u64| {
+        captured_teardowns.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    })?;
+    benchmarks.set_batching("batched", Duration::from_micros(200))?;
+    benchmarks.run()?;
+
+    assert_eq!(
+        setups.load(Ordering::Relaxed),
+        teardowns.load(Ordering::Relaxed)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_batching_zero_duration() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_closure(
+        "batched",
+        |_stop_watch: &mut StopWatch, _config: &str, _work: u64| Ok::<(), anyhow::Error>(()),
+        "no configuration",
+        vec![1],
+        5,
+        0,
+    )?;
+    assert!(benchmarks.set_batching("batched", Duration::ZERO).is_err());
+    Ok(())
+}
+
+static LIVE_WORKLOADS: AtomicUsize = AtomicUsize::new(0);
+static PEAK_WORKLOADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Workload point that counts its live clones
+struct Tracked(u64);
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        let live = LIVE_WORKLOADS.fetch_add(1, Ordering::Relaxed) + 1;
+        PEAK_WORKLOADS.fetch_max(live, Ordering::Relaxed);
+        Tracked(self.0)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        LIVE_WORKLOADS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Display for Tracked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[test]
+fn test_batching_bounded_arguments() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_closure(
+        "batched",
+        |_stop_watch: &mut StopWatch, _config: &str, work: Tracked| {
+            let _hash = work.0.wrapping_mul(0x9E3779B97F4A7C15);
+            Ok::<(), anyhow::Error>(())
+        },
+        "no configuration",
+        vec![Tracked(1)],
+        3,
+        0,
+    )?;
+    benchmarks.set_batching("batched", Duration::from_millis(20))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let run_summary = &summary.series()["batched"].runs()[0].1;
+    assert!(run_summary.batch() > 4096, "batch: {}", run_summary.batch());
+    let peak = PEAK_WORKLOADS.load(Ordering::Relaxed);
+    assert!(peak <= 1024 + 1, "peak: {peak}");
+    Ok(())
+}
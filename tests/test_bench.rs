@@ -1,8 +1,16 @@
+#![allow(
+    clippy::assertions_on_constants,
+    clippy::for_kv_map,
+    clippy::unnecessary_cast
+)]
+
 use benchmark_rs::benchmark_comparison::BenchmarkComparison;
 use benchmark_rs::benchmarks::Benchmarks;
 use benchmark_rs::stopwatch::StopWatch;
 use rand::Rng;
 use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::thread::sleep;
 use std::time::Duration;
 
@@ -236,6 +244,7 @@ fn test_analyze_different_runs_degradation() -> Result<(), anyhow::Error> {
                     previous,
                     current,
                     change,
+                    ..
                 } => {
                     assert!(false, "series_name: {series_name}, point: {point}, previous: {previous}, current: {current}, change: {change}");
                 }
@@ -244,6 +253,7 @@ fn test_analyze_different_runs_degradation() -> Result<(), anyhow::Error> {
                     previous,
                     current,
                     change,
+                    ..
                 } => {
                     assert!(false, "series_name: {series_name}, point: {point}, previous: {previous}, current: {current}, change: {change}");
                 }
@@ -252,6 +262,7 @@ fn test_analyze_different_runs_degradation() -> Result<(), anyhow::Error> {
                     previous,
                     current,
                     change,
+                    ..
                 } => {
                     assert!(true, "series_name: {series_name}, point: {point}, previous: {previous}, current: {current}, change: {change}");
                 }
@@ -313,6 +324,7 @@ fn test_analyze_different_runs_improvement() -> Result<(), anyhow::Error> {
                     previous,
                     current,
                     change,
+                    ..
                 } => {
                     assert!(true, "series_name: {series_name}, point: {point}, previous: {previous}, current: {current}, change: {change}");
                 }
@@ -321,6 +333,7 @@ fn test_analyze_different_runs_improvement() -> Result<(), anyhow::Error> {
                     previous,
                     current,
                     change,
+                    ..
                 } => {
                     assert!(false, "series_name: {series_name}, point: {point}, previous: {previous}, current: {current}, change: {change}");
                 }
@@ -329,6 +342,7 @@ fn test_analyze_different_runs_improvement() -> Result<(), anyhow::Error> {
                     previous,
                     current,
                     change,
+                    ..
                 } => {
                     assert!(false, "series_name: {series_name}, point: {point}, previous: {previous}, current: {current}, change: {change}");
                 }
@@ -359,6 +373,30 @@ fn test_csv() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[test]
+fn test_closure() -> Result<(), anyhow::Error> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let captured_calls = calls.clone();
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_closure(
+        "count calls",
+        move |_stop_watch: &mut StopWatch, _config: BenchConfig, _work: usize| {
+            captured_calls.fetch_add(1, Ordering::SeqCst);
+            Ok::<(), anyhow::Error>(())
+        },
+        BenchConfig::new(0),
+        vec![1, 2, 3],
+        3,
+        2,
+    )?;
+    benchmarks.add("sort n", bench_sort, BenchConfig::new(0), vec![10], 2, 1)?;
+    benchmarks.run()?;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 3 * (3 + 2));
+    assert_eq!(benchmarks.summary().series().len(), 2);
+    Ok(())
+}
+
 fn bench_sort(
     stop_watch: &mut StopWatch,
     config: BenchConfig,
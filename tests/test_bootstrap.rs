@@ -0,0 +1,111 @@
+mod common;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::bootstrap::Bootstrap;
+use benchmark_rs::comparison::{compare_runs, ComparisonOptions};
+use benchmark_rs::series_summary::SeriesSummary;
+use benchmark_rs::summary::Summary;
+use common::{sleep_benchmarks, RunSummaryBuilder};
+
+#[test]
+fn test_set_bootstrap() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(10, 1)?;
+    assert!(benchmarks
+        .set_bootstrap(Some(Bootstrap::new(100.0, 1000, 0)))
+        .is_err());
+    assert!(benchmarks
+        .set_bootstrap(Some(Bootstrap::new(95.0, 0, 0)))
+        .is_err());
+    benchmarks.set_bootstrap(Some(Bootstrap::default()))?;
+    benchmarks.set_bootstrap(None)?;
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    let (_point, run) = &summary.series()["sleep"].runs()[0];
+    assert!(run.median_ci().is_none());
+    assert!(run.mean_ci().is_none());
+    assert!(!benchmarks.summary_as_json().contains("median_ci"));
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(10, 1)?;
+    benchmarks.set_bootstrap(Some(Bootstrap::new(90.0, 500, 42)))?;
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    for (_point, run) in summary.series()["sleep"].runs() {
+        let median_ci = run.median_ci().unwrap();
+        assert_eq!(median_ci.confidence(), 90.0);
+        assert!(run.min_nanos() <= median_ci.lower_nanos().round() as u64);
+        assert!(median_ci.lower_nanos() <= median_ci.upper_nanos());
+        assert!(median_ci.upper_nanos().round() as u64 <= run.max_nanos());
+        let mean_ci = run.mean_ci().unwrap();
+        assert!(mean_ci.lower_nanos() <= run.mean_nanos());
+        assert!(run.mean_nanos() <= mean_ci.upper_nanos());
+    }
+
+    let loaded = Summary::from_json(&benchmarks.summary_as_json())?;
+    let (_point, loaded_run) = &loaded.series()["sleep"].runs()[0];
+    let (_point, run) = &summary.series()["sleep"].runs()[0];
+    assert_eq!(loaded_run.median_ci(), run.median_ci());
+    assert_eq!(loaded_run.mean_ci(), run.mean_ci());
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_csv() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(10, 1)?;
+    benchmarks.set_bootstrap(Some(Bootstrap::default()))?;
+    benchmarks.set_percentiles(&[])?;
+    benchmarks.set_csv_statistics(true);
+    benchmarks.run()?;
+    let csv = &benchmarks.summary_as_csv(true, false)["sleep"];
+    assert_eq!(
+        csv[0],
+        "point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec,mean_sec,iqr_sec,mad_sec,cv,\
+         median_ci_lower_sec,median_ci_upper_sec,mean_ci_lower_sec,mean_ci_upper_sec,status"
+    );
+    assert_eq!(csv[1].split(',').count(), 16);
+    let series = SeriesSummary::from_csv("sleep", &csv.join("\n"))?;
+    assert_eq!(series.runs().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_compare_overlapping_intervals() {
+    let previous = RunSummaryBuilder::new("sleep")
+        .median(1000)
+        .median_ci(900, 1100)
+        .build();
+    let current = RunSummaryBuilder::new("sleep")
+        .median(1050)
+        .median_ci(1000, 1200)
+        .build();
+    let mut options = ComparisonOptions::new(1.0);
+    assert!(!options.confidence_intervals());
+    assert!(matches!(
+        compare_runs("1", &current, &previous, &options).unwrap(),
+        BenchmarkComparison::Greater { .. }
+    ));
+
+    options.set_confidence_intervals(true);
+    assert!(matches!(
+        compare_runs("1", &current, &previous, &options).unwrap(),
+        BenchmarkComparison::Equal { .. }
+    ));
+
+    let disjoint = RunSummaryBuilder::new("sleep")
+        .median(1250)
+        .median_ci(1150, 1300)
+        .build();
+    assert!(matches!(
+        compare_runs("1", &disjoint, &previous, &options).unwrap(),
+        BenchmarkComparison::Greater { .. }
+    ));
+
+    let without_ci = RunSummaryBuilder::new("sleep").median(1050).build();
+    assert!(matches!(
+        compare_runs("1", &without_ci, &previous, &options).unwrap(),
+        BenchmarkComparison::Greater { .. }
+    ));
+}
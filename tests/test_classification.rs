@@ -0,0 +1,99 @@
+use std::thread;
+use std::time::Duration;
+
+use benchmark_rs::analysis_result::Classification;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::{compare_summaries, ComparisonOptions};
+use benchmark_rs::metric::Metric;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::summary::Summary;
+
+/// Run series that sleep for the given milliseconds at workload points 1 and 2
+fn summary(series: &[(&str, [u64; 2])]) -> Result<Summary, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    for (name, millis) in series {
+        let millis = *millis;
+        benchmarks.add_closure(
+            name,
+            move |_stop_watch: &mut StopWatch, _config: &str, work: usize| {
+                thread::sleep(Duration::from_millis(millis[work - 1]));
+                Ok::<(), anyhow::Error>(())
+            },
+            "sleep",
+            vec![1, 2],
+            3,
+            0,
+        )?;
+    }
+    benchmarks.run()?;
+    Ok(benchmarks.summary())
+}
+
+#[test]
+fn test_classification() -> Result<(), anyhow::Error> {
+    let previous = summary(&[
+        ("equal", [5, 5]),
+        ("regressed", [5, 5]),
+        ("improved", [15, 15]),
+        ("mixed", [5, 15]),
+    ])?;
+    let current = summary(&[
+        ("equal", [5, 5]),
+        ("regressed", [5, 20]),
+        ("improved", [5, 5]),
+        ("mixed", [15, 5]),
+    ])?;
+
+    let mut options = ComparisonOptions::new(50.0);
+    let analysis_result = compare_summaries(&current, &previous, &options)?;
+    assert_eq!(
+        analysis_result.classification("equal"),
+        Some(Classification::Equal)
+    );
+    assert_eq!(
+        analysis_result.classification("regressed"),
+        Some(Classification::Regressed)
+    );
+    assert_eq!(
+        analysis_result.classification("improved"),
+        Some(Classification::Improved)
+    );
+    assert_eq!(
+        analysis_result.classification("mixed"),
+        Some(Classification::Mixed)
+    );
+    assert_eq!(analysis_result.classification("missing"), None);
+    assert!(analysis_result.regressed_series().contains("regressed"));
+    assert!(analysis_result.improved_series().contains("improved"));
+    assert!(analysis_result.mixed_series().contains("mixed"));
+    assert_eq!(analysis_result.divergent_series().len(), 3);
+    assert_eq!(analysis_result.regression_count(), 2);
+    assert_eq!(analysis_result.improvement_count(), 3);
+    assert!(analysis_result.has_regressions());
+    assert!(analysis_result.has_improvements());
+
+    let worst = analysis_result.worst_regressions(1);
+    assert_eq!(worst.len(), 1);
+    assert_eq!(worst[0].0, "regressed");
+    assert_eq!(worst[0].1.point(), "2");
+    assert_eq!(analysis_result.worst_regressions(10).len(), 2);
+    let best = analysis_result.best_improvements(10);
+    assert_eq!(best.len(), 3);
+    assert!(best[0].1.change() <= best[2].1.change());
+
+    // slower runs have lower throughput, which is still a regression
+    options.set_metric(Metric::Throughput)?;
+    let analysis_result = compare_summaries(&current, &previous, &options)?;
+    assert_eq!(
+        analysis_result.classification("regressed"),
+        Some(Classification::Regressed)
+    );
+    assert_eq!(
+        analysis_result.classification("improved"),
+        Some(Classification::Improved)
+    );
+    let worst = analysis_result.worst_regressions(1);
+    assert!(worst[0].1.change() < 0.0);
+    assert!(worst[0].1.regression() > 0.0);
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::stopwatch::StopWatch;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// Configuration that is expensive to clone
+struct SlowConfig {
+    clones: Arc<AtomicUsize>,
+}
+
+impl Clone for SlowConfig {
+    fn clone(&self) -> Self {
+        sleep(Duration::from_millis(20));
+        self.clones.fetch_add(1, Ordering::SeqCst);
+        SlowConfig {
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+impl Display for SlowConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("slow clone")
+    }
+}
+
+fn by_value(
+    _stop_watch: &mut StopWatch,
+    _config: SlowConfig,
+    _work: u64,
+) -> Result<(), anyhow::Error> {
+    Ok(())
+}
+
+fn by_ref(
+    _stop_watch: &mut StopWatch,
+    _config: &SlowConfig,
+    _work: &u64,
+) -> Result<(), anyhow::Error> {
+    Ok(())
+}
+
+#[test]
+fn test_clone_not_measured() -> Result<(), anyhow::Error> {
+    let clones = Arc::new(AtomicUsize::new(0));
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add(
+        "by value",
+        by_value,
+        SlowConfig {
+            clones: clones.clone(),
+        },
+        vec![1],
+        3,
+        0,
+    )?;
+    benchmarks.run()?;
+
+    assert_eq!(clones.load(Ordering::SeqCst), 3);
+    let summary = benchmarks.summary();
+    let (_point, run_summary) = &summary.series()["by value"].runs()[0];
+    assert!(run_summary.max_nanos() < Duration::from_millis(20).as_nanos() as u64);
+    Ok(())
+}
+
+#[test]
+fn test_by_ref_not_cloned() -> Result<(), anyhow::Error> {
+    let clones = Arc::new(AtomicUsize::new(0));
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_by_ref(
+        "by reference",
+        by_ref,
+        SlowConfig {
+            clones: clones.clone(),
+        },
+        vec![1, 2],
+        3,
+        1,
+    )?;
+    benchmarks.run()?;
+
+    assert_eq!(clones.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        benchmarks.summary().series()["by reference"].runs().len(),
+        2
+    );
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::stopwatch::StopWatch;
+
+fn save_summary(dir: &Path, slowdown: u64) -> Result<PathBuf, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_closure(
+        "sleep",
+        move |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            thread::sleep(Duration::from_millis(work * slowdown));
+            Ok::<(), anyhow::Error>(())
+        },
+        "sleep",
+        vec![10, 20],
+        3,
+        0,
+    )?;
+    benchmarks.run()?;
+    benchmarks.save_to_json(dir.to_path_buf())?;
+    Ok(dir.join("Test.json"))
+}
+
+fn save_failed_summary(dir: &Path) -> Result<PathBuf, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_closure(
+        "sleep",
+        move |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            if work == 20 {
+                return Err(anyhow::anyhow!("failed at {work}"));
+            }
+            thread::sleep(Duration::from_millis(work));
+            Ok(())
+        },
+        "sleep",
+        vec![10, 20],
+        3,
+        0,
+    )?;
+    benchmarks.set_continue_on_error(true);
+    benchmarks.run()?;
+    benchmarks.save_to_json(dir.to_path_buf())?;
+    Ok(dir.join("Test.json"))
+}
+
+fn compare(args: &[&Path], options: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_benchmark-rs"))
+        .arg("compare")
+        .args(args)
+        .args(options)
+        .output()
+        .expect("failed to run benchmark-rs");
+    (
+        output.status.code().expect("exited with a code"),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn test_compare() -> Result<(), anyhow::Error> {
+    let dir = PathBuf::from("target/test-compare-cli");
+    let previous = save_summary(&dir.join("previous"), 1)?;
+    let current = save_summary(&dir.join("current"), 3)?;
+
+    let (code, stdout) = compare(&[&previous, &current], &["--threshold", "20"]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("regressed"));
+
+    let (code, stdout) = compare(&[&current, &previous], &["--threshold=20"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("improved"));
+
+    let (code, stdout) = compare(&[&previous, &previous], &["--format", "json"]);
+    assert_eq!(code, 0);
+    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(json["name"], "Test");
+    assert!(json["equal_series"]["sleep"].is_object());
+
+    let (code, stdout) = compare(
+        &[&previous, &current],
+        &["--threshold", "20", "--metric", "throughput"],
+    );
+    assert_eq!(code, 1);
+    assert!(stdout.contains("Metric: throughput"));
+    assert!(stdout.contains("ops/s"));
+    assert!(stdout.contains("regressed"));
+
+    // summaries without samples are compared by the threshold alone
+    let (code, stdout) = compare(
+        &[&previous, &current],
+        &["--threshold", "20", "--significance", "mann-whitney"],
+    );
+    assert_eq!(code, 1);
+    assert!(stdout.contains("regressed"));
+    let (code, _stdout) = compare(&[&previous, &current], &["--significance", "t-test"]);
+    assert_eq!(code, 2);
+
+    let (code, _stdout) = compare(&[&previous], &[]);
+    assert_eq!(code, 2);
+    let (code, _stdout) = compare(&[&previous, &dir.join("missing.json")], &[]);
+    assert_eq!(code, 2);
+    Ok(())
+}
+
+#[test]
+fn test_compare_failures() -> Result<(), anyhow::Error> {
+    let dir = PathBuf::from("target/test-compare-cli-failures");
+    let previous = save_summary(&dir.join("previous"), 1)?;
+    let failed = save_failed_summary(&dir.join("failed"))?;
+
+    // the failed point is not compared, the remaining point is equal
+    let (code, stdout) = compare(&[&previous, &failed], &["--threshold", "1000"]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("failed at 20"));
+    assert!(!stdout.contains("regressed"));
+
+    let (code, _stdout) = compare(&[&previous, &previous], &["--threshold", "1000"]);
+    assert_eq!(code, 0);
+    Ok(())
+}
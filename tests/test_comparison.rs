@@ -0,0 +1,80 @@
+use std::thread;
+use std::time::Duration;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::{
+    compare_runs, compare_series, compare_summaries, ComparisonOptions,
+};
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::summary::Summary;
+
+fn run_summary(name: &str, slowdown: u64) -> Result<Summary, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new(name);
+    benchmarks.add_closure(
+        "sleep",
+        move |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            thread::sleep(Duration::from_millis(work * slowdown));
+            Ok::<(), anyhow::Error>(())
+        },
+        "sleep",
+        vec![5, 10],
+        3,
+        0,
+    )?;
+    benchmarks.run()?;
+    Ok(benchmarks.summary())
+}
+
+#[test]
+fn test_compare_summaries() -> Result<(), anyhow::Error> {
+    let previous = run_summary("Test", 1)?;
+    let current = run_summary("Test", 3)?;
+    let options = ComparisonOptions::new(20.0);
+
+    let analysis_result = compare_summaries(&current, &previous, &options)?;
+    assert!(analysis_result.has_regressions());
+    assert_eq!(analysis_result.divergent_series()["sleep"].len(), 2);
+
+    let analysis_result = compare_summaries(&previous, &current, &options)?;
+    assert!(!analysis_result.has_regressions());
+    assert_eq!(analysis_result.divergent_series()["sleep"].len(), 2);
+
+    let analysis_result = current.analyze(&previous, 1000.0)?;
+    assert_eq!(analysis_result.equal_series()["sleep"].len(), 2);
+
+    let other = run_summary("Other", 1)?;
+    assert!(compare_summaries(&other, &previous, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_compare_series_and_runs() -> Result<(), anyhow::Error> {
+    let previous = run_summary("Test", 1)?;
+    let current = run_summary("Test", 3)?;
+    let mut options = ComparisonOptions::default();
+    assert_eq!(options.threshold(), 5.0);
+    options.set_threshold(20.0);
+
+    let comparisons = compare_series(
+        &current.series()["sleep"],
+        &previous.series()["sleep"],
+        &options,
+    )?;
+    assert!(matches!(
+        comparisons["10"],
+        BenchmarkComparison::Greater { .. }
+    ));
+
+    let (point, current_run) = &current.series()["sleep"].runs()[0];
+    let (_point, previous_run) = &previous.series()["sleep"].runs()[0];
+    assert!(matches!(
+        compare_runs(point, previous_run, current_run, &options)?,
+        BenchmarkComparison::Less { .. }
+    ));
+    assert!(matches!(
+        compare_runs(point, current_run, current_run, &options)?,
+        BenchmarkComparison::Equal { .. }
+    ));
+    Ok(())
+}
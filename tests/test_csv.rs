@@ -0,0 +1,117 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::failure::FailureKind;
+use benchmark_rs::series_summary::SeriesSummary;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::summary::Summary;
+
+fn sum_or_fail(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    if work == 3 {
+        Err(anyhow!("failed on {work}"))
+    } else {
+        let _sum: u64 = (0..work * 1000).sum();
+        Ok(())
+    }
+}
+
+fn benchmarks() -> Result<Benchmarks<&'static str, u64, anyhow::Error>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("csv");
+    benchmarks.add("sum", sum_or_fail, "1, 2, 3", vec![1, 2, 3, 4], 3, 1)?;
+    benchmarks.add("sum again", sum_or_fail, "none", vec![1, 2], 3, 1)?;
+    benchmarks.set_continue_on_error(true);
+    benchmarks.run()?;
+    Ok(benchmarks)
+}
+
+#[test]
+fn test_round_trip() -> Result<(), anyhow::Error> {
+    let benchmarks = benchmarks()?;
+    let summary = benchmarks.summary();
+    for (with_headers, with_config) in [(true, true), (true, false), (false, false)] {
+        let dir = PathBuf::from(format!(
+            "target/test-csv/round-trip-{with_headers}-{with_config}"
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        benchmarks.save_to_csv(dir.clone(), with_headers, with_config)?;
+
+        let loaded = Summary::load_csv_dir(&dir, "csv")?;
+        assert_eq!(loaded.name(), "csv");
+        assert_eq!(loaded.series().len(), 2);
+        let series = &loaded.series()["sum"];
+        let expected = &summary.series()["sum"];
+        assert_eq!(series.name(), "sum");
+        if with_config {
+            assert_eq!(series.config(), "1, 2, 3");
+        } else {
+            assert_eq!(series.config(), "");
+        }
+        assert_eq!(series.runs().len(), 3);
+        for ((point, run), (expected_point, expected_run)) in
+            series.runs().iter().zip(expected.runs())
+        {
+            assert_eq!(point, expected_point);
+            assert_eq!(run.median_nanos(), expected_run.median_nanos());
+            assert_eq!(run.min_nanos(), expected_run.min_nanos());
+        }
+        assert_eq!(series.failures().len(), 1);
+        assert_eq!(series.failures()[0].point(), Some(&"3".to_string()));
+        assert_eq!(series.failures()[0].kind(), FailureKind::Error);
+
+        let analysis_result = benchmarks.summary().analyze(&loaded, 5.0)?;
+        assert_eq!(analysis_result.equal_series().len(), 2);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_single_sample_round_trip() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("csv");
+    benchmarks.add("sum", sum_or_fail, "none", vec![1], 1, 0)?;
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    assert_eq!(summary.series()["sum"].runs()[0].1.std_dev(), None);
+
+    let dir = PathBuf::from("target/test-csv/single-sample");
+    let _ = fs::remove_dir_all(&dir);
+    benchmarks.save_to_csv(dir.clone(), true, true)?;
+    // a missing standard deviation is written as 0
+    let loaded = Summary::load_csv_dir(&dir, "csv")?;
+    assert_eq!(loaded.series()["sum"].runs()[0].1.std_dev(), Some(0.0));
+
+    let csv = "1,1,1,0.001,0.001,0.001,NaN\n";
+    let series = SeriesSummary::from_csv("nan", csv)?;
+    assert_eq!(series.runs()[0].1.std_dev(), None);
+    let csv = "1,1,1,0.001,0.001,0.001,,ok\n";
+    let series = SeriesSummary::from_csv("empty", csv)?;
+    assert_eq!(series.runs()[0].1.std_dev(), None);
+    Ok(())
+}
+
+#[test]
+fn test_layout_without_status() -> Result<(), anyhow::Error> {
+    let csv = "\
+point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec,,configuration: 0, 1, 2
+1,1,2,0.001265417,0.001266709,0.001266063,0.0000009135819612930194
+2,1,2,0.002518501,0.002534999,0.00252675,0.000011665847676015662
+";
+    let series = SeriesSummary::from_csv("old", csv)?;
+    assert_eq!(series.config(), "0, 1, 2");
+    assert_eq!(series.runs().len(), 2);
+    assert_eq!(series.runs()[1].0, "2");
+    assert_eq!(series.runs()[1].1.median_nanos(), 2526750);
+    assert!(!series.has_failures());
+    Ok(())
+}
+
+#[test]
+fn test_invalid_csv() {
+    assert!(SeriesSummary::from_csv("bad", "1,1,2,0.1\n").is_err());
+    assert!(SeriesSummary::from_csv("bad", "1,1,2,0.1,0.2,0.15,x,0.01\n").is_err());
+    assert!(SeriesSummary::from_csv("bad", "1,1,2,0.1,0.2,0.15,0.01,melted\n").is_err());
+    let e =
+        SeriesSummary::from_csv_file(&PathBuf::from("target/test-csv/missing.csv")).unwrap_err();
+    assert!(format!("{e:#}").contains("missing.csv"));
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::new_without_default)]
+
 use benchmark_rs::benchmarks::Benchmarks;
 use benchmark_rs::stopwatch::StopWatch;
 use std::fmt::{Display, Formatter};
@@ -0,0 +1,137 @@
+use anyhow::anyhow;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::failure::FailureKind;
+use benchmark_rs::stopwatch::StopWatch;
+
+fn fail_on_two(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    if work == 2 {
+        Err(anyhow!("failed on {work}"))
+    } else {
+        Ok(())
+    }
+}
+
+fn succeed(_stop_watch: &mut StopWatch, _config: &str, _work: u64) -> Result<(), anyhow::Error> {
+    Ok(())
+}
+
+#[test]
+fn test_stop_on_error() {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks
+        .add("failing", fail_on_two, "fail", vec![1, 2, 3], 2, 1)
+        .unwrap();
+    assert!(benchmarks.run().is_err());
+}
+
+#[test]
+fn test_continue_on_error() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("failing", fail_on_two, "fail", vec![1, 2, 3], 2, 1)?;
+    benchmarks.add("succeeding", succeed, "succeed", vec![1, 2, 3], 2, 1)?;
+    benchmarks.set_continue_on_error(true);
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let failing = &summary.series()["failing"];
+    let points: Vec<&String> = failing.runs().iter().map(|(point, _)| point).collect();
+    assert_eq!(points, vec!["1", "3"]);
+    assert_eq!(failing.failures().len(), 1);
+    let failure = &failing.failures()[0];
+    assert_eq!(failure.point(), Some(&"2".to_string()));
+    assert_eq!(failure.iteration(), 0);
+    assert_eq!(failure.kind(), FailureKind::Error);
+    assert_eq!(failure.message(), "failed on 2");
+    assert!(!summary.series()["succeeding"].has_failures());
+
+    let csv = &benchmarks.summary_as_csv(true, false)["failing"];
+    assert!(csv[0].ends_with(",status"));
+    assert!(csv[1].ends_with(",ok"));
+    assert_eq!(csv[3], "2,,,,,,,error");
+
+    let analysis_result = benchmarks.analyze(Some(benchmarks.summary_as_json()), 5.0)?;
+    assert_eq!(analysis_result.failed_series()["failing"].len(), 1);
+    assert_eq!(analysis_result.equal_series()["failing"].len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_continue_on_series_setup_error() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("setup fails", succeed, "succeed", vec![1, 2, 3], 2, 1)?;
+    benchmarks.set_series_setup("setup fails", |_config: &&str| Err(anyhow!("no setup")))?;
+    benchmarks.set_continue_on_error(true);
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let failures = summary.series()["setup fails"].failures();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].point(), None);
+
+    let analysis_result = benchmarks.analyze(Some(benchmarks.summary_as_json()), 5.0)?;
+    assert!(analysis_result.failed_series().contains_key("setup fails"));
+    assert!(analysis_result.equal_series().is_empty());
+    Ok(())
+}
+
+fn panic_on_two(
+    _stop_watch: &mut StopWatch,
+    _config: &str,
+    work: u64,
+) -> Result<(), anyhow::Error> {
+    if work == 2 {
+        panic!("panicked on {work}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_panic() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("panicking", panic_on_two, "panic", vec![1, 2, 3], 2, 1)?;
+    benchmarks.add("succeeding", succeed, "succeed", vec![1, 2, 3], 2, 1)?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    assert!(summary.has_failures());
+    let panicking = &summary.series()["panicking"];
+    assert_eq!(panicking.runs().len(), 2);
+    let failure = &panicking.failures()[0];
+    assert_eq!(failure.point(), Some(&"2".to_string()));
+    assert_eq!(failure.kind(), FailureKind::Panic);
+    assert_eq!(failure.message(), "panicked on 2");
+    assert_eq!(summary.series()["succeeding"].runs().len(), 3);
+
+    let csv = &benchmarks.summary_as_csv(false, false)["panicking"];
+    assert_eq!(csv[2], "2,,,,,,,panic");
+
+    let analysis_result = benchmarks.analyze(Some(benchmarks.summary_as_json()), 5.0)?;
+    assert_eq!(
+        analysis_result.failed_series()["panicking"][0].kind(),
+        FailureKind::Panic
+    );
+    Ok(())
+}
+
+#[test]
+fn test_panic_in_teardown() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("teardown", succeed, "succeed", vec![1, 2], 2, 1)?;
+    benchmarks.set_point_teardown("teardown", |_config: &&str, work: &u64| {
+        if *work == 1 {
+            panic!("teardown panicked");
+        }
+        Ok(())
+    })?;
+    benchmarks.set_series_teardown("teardown", |_config: &&str| panic!("series teardown"))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let failures = summary.series()["teardown"].failures();
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].point(), Some(&"1".to_string()));
+    assert_eq!(failures[0].message(), "teardown panicked");
+    assert_eq!(failures[1].point(), None);
+    assert_eq!(failures[1].message(), "series teardown");
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::filter::{Filter, NamePattern};
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::threading::Threaded;
+
+fn sum(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    let _sum: u64 = (0..work).sum();
+    Ok(())
+}
+
+fn benchmarks() -> Result<Benchmarks<&'static str, u64, anyhow::Error, Threaded>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new_threaded("Test");
+    for name in ["vec push", "vec insert", "map insert", "map lookup"] {
+        benchmarks.add(name, sum, "sum", vec![1, 2], 2, 0)?;
+    }
+    benchmarks.set_tags("vec insert", &["slow"])?;
+    benchmarks.set_tags("map lookup", &["slow", "memory"])?;
+    Ok(benchmarks)
+}
+
+fn run_names(filter: Filter) -> Result<Vec<String>, anyhow::Error> {
+    let mut benchmarks = benchmarks()?;
+    benchmarks.set_filter(filter);
+    benchmarks.run()?;
+    let mut names: Vec<String> = benchmarks.summary().series().keys().cloned().collect();
+    names.sort();
+    Ok(names)
+}
+
+#[test]
+fn test_name_patterns() -> Result<(), anyhow::Error> {
+    let mut filter = Filter::new();
+    filter.add_name(NamePattern::substring("insert"));
+    assert_eq!(run_names(filter)?, vec!["map insert", "vec insert"]);
+
+    let mut filter = Filter::new();
+    filter.add_name(NamePattern::glob("vec *"));
+    assert_eq!(run_names(filter)?, vec!["vec insert", "vec push"]);
+
+    let mut filter = Filter::new();
+    filter.add_name(NamePattern::regex("^map (insert|lookup)$")?);
+    filter.add_name(NamePattern::glob("vec pus?"));
+    assert_eq!(
+        run_names(filter)?,
+        vec!["map insert", "map lookup", "vec push"]
+    );
+
+    assert!(NamePattern::regex("(").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_name_pattern_serialization() -> Result<(), anyhow::Error> {
+    let pattern = NamePattern::glob("vec *");
+    let json = serde_json::to_string(&pattern)?;
+    assert_eq!(json, r#"{"Glob":"vec *"}"#);
+    let deserialized: NamePattern = serde_json::from_str(&json)?;
+    assert_eq!(deserialized, pattern);
+    assert!(deserialized.matches("vec push"));
+    assert!(serde_json::from_str::<NamePattern>(r#"{"Regex":"("}"#).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_tags() -> Result<(), anyhow::Error> {
+    let mut filter = Filter::new();
+    filter.add_tag("slow");
+    assert_eq!(run_names(filter)?, vec!["map lookup", "vec insert"]);
+
+    let mut filter = Filter::new();
+    filter.add_tag("slow");
+    filter.add_name(NamePattern::substring("map"));
+    assert_eq!(run_names(filter)?, vec!["map lookup"]);
+    Ok(())
+}
+
+#[test]
+fn test_summary_records_filter() -> Result<(), anyhow::Error> {
+    let mut benchmarks = benchmarks()?;
+    let mut filter = Filter::new();
+    filter.add_tag("memory");
+    benchmarks.set_filter(filter.clone());
+    benchmarks.run_parallel(2)?;
+
+    let summary = benchmarks.summary();
+    assert_eq!(summary.filter(), &filter);
+    assert_eq!(
+        summary.skipped(),
+        &vec!["vec push", "vec insert", "map insert"]
+    );
+    assert_eq!(summary.series().len(), 1);
+
+    let analysis_result = benchmarks.analyze(Some(benchmarks.summary_as_json()), 5.0)?;
+    assert_eq!(analysis_result.equal_series().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_tags_unknown_series() {
+    let mut benchmarks: Benchmarks<&str, u64, anyhow::Error> = Benchmarks::new("Test");
+    assert!(benchmarks.set_tags("missing", &["slow"]).is_err());
+}
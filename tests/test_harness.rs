@@ -0,0 +1,176 @@
+use std::path::PathBuf;
+
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::SignificanceTest;
+use benchmark_rs::filter::NamePattern;
+use benchmark_rs::harness::{self, parse_args, ComparisonArgs, HarnessArgs, OutputFormat};
+use benchmark_rs::metric::Metric;
+use benchmark_rs::stopwatch::StopWatch;
+
+fn sum(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    let _sum: u64 = (0..work).sum();
+    Ok(())
+}
+
+fn suite() -> Result<Benchmarks<&'static str, u64, anyhow::Error>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("harness");
+    benchmarks.add("sum small", sum, "small", vec![1, 2], 2, 0)?;
+    benchmarks.add("sum large", sum, "large", vec![100, 200], 2, 0)?;
+    Ok(benchmarks)
+}
+
+fn args(args: &[&str]) -> Result<HarnessArgs, anyhow::Error> {
+    HarnessArgs::parse(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn test_parse() -> Result<(), anyhow::Error> {
+    let parsed = args(&[
+        "--bench",
+        "--filter",
+        "glob:sum *",
+        "--filter=regex:^sum",
+        "small",
+        "--tag",
+        "fast",
+        "--baseline",
+        "main",
+        "--save-baseline=feature",
+        "--threshold",
+        "7.5",
+        "--output-dir",
+        "target/harness",
+        "--format",
+        "csv",
+        "--format=json",
+        "--metric",
+        "p99",
+        "--significance",
+        "welch",
+        "--significance-level=0.01",
+        "--color",
+        "always",
+    ])?;
+    assert_eq!(
+        parsed.filter().names(),
+        &vec![
+            NamePattern::glob("sum *"),
+            NamePattern::regex("^sum")?,
+            NamePattern::substring("small"),
+        ]
+    );
+    assert_eq!(parsed.filter().tags(), &vec!["fast".to_string()]);
+    assert_eq!(parsed.baseline(), Some(&"main".to_string()));
+    assert_eq!(parsed.save_baseline(), Some(&"feature".to_string()));
+    assert_eq!(parsed.threshold(), 7.5);
+    assert_eq!(parsed.output_dir(), &PathBuf::from("target/harness"));
+    assert_eq!(
+        parsed.formats(),
+        &vec![OutputFormat::Csv, OutputFormat::Json]
+    );
+    assert_eq!(parsed.metric(), Some(Metric::Percentile(99.0)));
+    assert_eq!(parsed.significance(), Some(SignificanceTest::Welch));
+    assert_eq!(parsed.significance_level(), 0.01);
+    assert!(!parsed.list());
+
+    let defaults = args(&["--bench"])?;
+    assert_eq!(defaults.formats(), &vec![OutputFormat::Json]);
+    assert_eq!(defaults.threshold(), 5.0);
+    assert_eq!(defaults.output_dir(), &PathBuf::from("target/benchmarks"));
+
+    assert!(args(&["--unknown"]).is_err());
+    assert!(args(&["--threshold", "many"]).is_err());
+    assert!(args(&["--format", "xml"]).is_err());
+    assert!(args(&["--significance", "chi-squared"]).is_err());
+    assert!(args(&["--metric", "max"]).is_err());
+    assert!(args(&["--baseline"]).is_err());
+    assert!(args(&["--filter", "regex:("]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_comparison_args() -> Result<(), anyhow::Error> {
+    let mut comparison = ComparisonArgs::default();
+    let positional = parse_args(
+        [
+            "previous.json",
+            "--threshold=2",
+            "--metric",
+            "mean",
+            "current.json",
+        ]
+        .map(String::from),
+        |flag, value| comparison.parse_option(flag, value),
+    )?;
+    assert_eq!(positional, vec!["previous.json", "current.json"]);
+    let options = comparison.comparison_options()?;
+    assert_eq!(options.threshold(), 2.0);
+    assert_eq!(options.metric(), Metric::Mean);
+
+    let mut comparison = ComparisonArgs::default();
+    assert!(parse_args(["--list".to_string()], |flag, value| {
+        comparison.parse_option(flag, value)
+    })
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_list() -> Result<(), anyhow::Error> {
+    let mut benchmarks = suite()?;
+    let analysis_result = harness::run(&mut benchmarks, &args(&["--list", "large"])?)?;
+    assert!(analysis_result.is_none());
+    assert!(benchmarks.summary().series().is_empty());
+    assert_eq!(benchmarks.selected_names(), vec!["sum large"]);
+    Ok(())
+}
+
+#[test]
+fn test_baselines() -> Result<(), anyhow::Error> {
+    let output_dir = "target/test-harness";
+    let _ = std::fs::remove_dir_all(output_dir);
+
+    let mut benchmarks = suite()?;
+    let analysis_result = harness::run(
+        &mut benchmarks,
+        &args(&[
+            "--output-dir",
+            output_dir,
+            "--save-baseline",
+            "main",
+            "--format",
+            "json",
+            "--format",
+            "csv",
+        ])?,
+    )?;
+    assert!(analysis_result.is_none());
+    assert!(PathBuf::from(output_dir).join("harness.json").exists());
+    assert!(PathBuf::from(output_dir).join("sum small.csv").exists());
+    assert!(PathBuf::from(output_dir)
+        .join("baselines/main/harness.json")
+        .exists());
+
+    let mut benchmarks = suite()?;
+    let analysis_result = harness::run(
+        &mut benchmarks,
+        &args(&[
+            "--output-dir",
+            output_dir,
+            "--baseline",
+            "main",
+            "--threshold",
+            "1000",
+        ])?,
+    )?
+    .expect("compared with baseline");
+    assert_eq!(analysis_result.equal_series().len(), 2);
+
+    let mut benchmarks = suite()?;
+    assert!(harness::run(
+        &mut benchmarks,
+        &args(&["--output-dir", output_dir, "--baseline", "missing"])?,
+    )
+    .is_err());
+    Ok(())
+}
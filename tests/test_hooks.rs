@@ -0,0 +1,245 @@
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::stopwatch::StopWatch;
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+#[test]
+fn test_hooks_order() -> Result<(), anyhow::Error> {
+    let events = Arc::new(Mutex::new(Vec::<String>::new()));
+    let mut benchmarks = Benchmarks::new("Test");
+    let e = events.clone();
+    benchmarks.add_with_setup(
+        "hooks",
+        move |_config: &&str, work: &u64| {
+            e.lock().unwrap().push(format!("point setup {work}"));
+            Ok(work.to_string())
+        },
+        |_stop_watch: &mut StopWatch, _config: &str, input: String| {
+            assert_eq!(input, "1");
+            Ok::<(), anyhow::Error>(())
+        },
+        "no configuration",
+        vec![1],
+        1,
+        1,
+    )?;
+
+    let e = events.clone();
+    benchmarks.set_setup(move || {
+        e.lock().unwrap().push("suite setup".to_string());
+        Ok(())
+    });
+    let e = events.clone();
+    benchmarks.set_teardown(move || {
+        e.lock().unwrap().push("suite teardown".to_string());
+        Ok(())
+    });
+    let e = events.clone();
+    benchmarks.set_series_setup("hooks", move |_config: &&str| {
+        e.lock().unwrap().push("series setup".to_string());
+        Ok(())
+    })?;
+    let e = events.clone();
+    benchmarks.set_series_teardown("hooks", move |_config: &&str| {
+        e.lock().unwrap().push("series teardown".to_string());
+        Ok(())
+    })?;
+    let e = events.clone();
+    benchmarks.set_point_teardown("hooks", move |_config: &&str, work: &u64| {
+        e.lock().unwrap().push(format!("point teardown {work}"));
+        Ok(())
+    })?;
+    benchmarks.run()?;
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            "suite setup",
+            "series setup",
+            "point setup 1",
+            "point teardown 1",
+            "point setup 1",
+            "point teardown 1",
+            "series teardown",
+            "suite teardown",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_hooks_not_measured() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_with_setup(
+        "not measured",
+        |_config: &&str, work: &u64| {
+            sleep(Duration::from_millis(20));
+            Ok(*work)
+        },
+        |_stop_watch: &mut StopWatch, _config: &str, _input: u64| Ok::<(), anyhow::Error>(()),
+        "no configuration",
+        vec![1],
+        3,
+        0,
+    )?;
+    benchmarks.set_point_teardown("not measured", |_config: &&str, _work: &u64| {
+        sleep(Duration::from_millis(20));
+        Ok(())
+    })?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let (_point, run_summary) = &summary.series()["not measured"].runs()[0];
+    assert!(run_summary.max_nanos() < Duration::from_millis(20).as_nanos() as u64);
+    Ok(())
+}
+
+#[test]
+fn test_hooks_input_teardown() -> Result<(), anyhow::Error> {
+    let events = Arc::new(Mutex::new(Vec::<String>::new()));
+    let mut benchmarks = Benchmarks::new("Test");
+    let (setup_events, call_events, teardown_events) =
+        (events.clone(), events.clone(), events.clone());
+    let mut next = 0;
+    benchmarks.add_with_setup_and_teardown(
+        "input",
+        move |_config: &&str, _work: &u64| {
+            next += 1;
+            setup_events.lock().unwrap().push(format!("setup {next}"));
+            Ok(next)
+        },
+        move |_stop_watch: &mut StopWatch, _config: &str, input: &mut u64| {
+            call_events.lock().unwrap().push(format!("call {input}"));
+            Ok::<(), anyhow::Error>(())
+        },
+        move |_config: &&str, _work: &u64, input: u64| {
+            teardown_events
+                .lock()
+                .unwrap()
+                .push(format!("teardown {input}"));
+            Ok(())
+        },
+        "no configuration",
+        vec![1],
+        2,
+        0,
+    )?;
+    benchmarks.set_batching("input", Duration::from_micros(200))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let (_point, run_summary) = &summary.series()["input"].runs()[0];
+    assert!(run_summary.batch() > 1);
+    let events = events.lock().unwrap();
+    assert_eq!(events.len() % 3, 0);
+    for (i, invocation) in events.chunks(3).enumerate() {
+        let input = i + 1;
+        assert_eq!(
+            invocation,
+            [
+                format!("setup {input}"),
+                format!("call {input}"),
+                format!("teardown {input}")
+            ]
+        );
+    }
+    Ok(())
+}
+
+fn input_setup(_config: &&str, work: &u64) -> Result<u64, anyhow::Error> {
+    Ok(*work)
+}
+
+fn fast(_stop_watch: &mut StopWatch, _config: &str, _input: &mut u64) -> Result<(), anyhow::Error> {
+    Ok(())
+}
+
+fn slow_input_teardown(_config: &&str, _work: &u64, _input: u64) -> Result<(), anyhow::Error> {
+    sleep(Duration::from_millis(30));
+    Ok(())
+}
+
+fn slow_point_teardown(_config: &&str, _work: &u64) -> Result<(), anyhow::Error> {
+    sleep(Duration::from_millis(30));
+    Ok(())
+}
+
+fn assert_not_timed(summary: &benchmark_rs::summary::Summary) {
+    let series = &summary.series()["teardowns"];
+    assert!(series.failures().is_empty(), "{:?}", series.failures());
+    assert_eq!(series.runs().len(), 2);
+}
+
+#[test]
+fn test_hooks_teardowns_not_timed() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_with_setup_and_teardown(
+        "teardowns",
+        input_setup,
+        fast,
+        slow_input_teardown,
+        "no configuration",
+        vec![1, 2],
+        3,
+        1,
+    )?;
+    benchmarks.set_point_teardown("teardowns", slow_point_teardown)?;
+    benchmarks.set_iteration_timeout("teardowns", Duration::from_millis(20))?;
+    benchmarks.set_point_timeout("teardowns", Duration::from_millis(100))?;
+    benchmarks.run()?;
+    assert_not_timed(&benchmarks.summary());
+    Ok(())
+}
+
+#[test]
+fn test_hooks_teardowns_not_timed_threaded() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new_threaded("Test");
+    benchmarks.add_with_setup_and_teardown(
+        "teardowns",
+        input_setup,
+        fast,
+        slow_input_teardown,
+        "no configuration",
+        vec![1, 2],
+        3,
+        1,
+    )?;
+    benchmarks.set_point_teardown("teardowns", slow_point_teardown)?;
+    benchmarks.set_iteration_timeout("teardowns", Duration::from_millis(20))?;
+    benchmarks.set_point_timeout("teardowns", Duration::from_millis(100))?;
+    benchmarks.run()?;
+    assert_not_timed(&benchmarks.summary());
+    Ok(())
+}
+
+#[test]
+fn test_suite_hooks_panic() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add_closure(
+        "hooks",
+        |_stop_watch: &mut StopWatch, _config: &str, _work: u64| Ok::<(), anyhow::Error>(()),
+        "no configuration",
+        vec![1],
+        1,
+        0,
+    )?;
+    benchmarks.set_setup(|| panic!("suite setup panicked"));
+    let e = benchmarks.run().unwrap_err();
+    assert!(format!("{e:#}").contains("suite setup panicked"));
+
+    benchmarks.set_setup(|| Ok(()));
+    benchmarks.set_teardown(|| panic!("suite teardown panicked"));
+    let e = benchmarks.run().unwrap_err();
+    assert!(format!("{e:#}").contains("suite teardown panicked"));
+    assert_eq!(benchmarks.summary().series()["hooks"].runs().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_hooks_unknown_benchmark() {
+    let mut benchmarks: Benchmarks<&str, u64, anyhow::Error> = Benchmarks::new("Test");
+    assert!(benchmarks
+        .set_series_setup("unknown", |_config: &&str| Ok(()))
+        .is_err());
+}
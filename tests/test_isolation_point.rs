@@ -0,0 +1,88 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use anyhow::anyhow;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::isolation::Isolation;
+use benchmark_rs::stopwatch::StopWatch;
+
+static RAN: AtomicBool = AtomicBool::new(false);
+static POINT: Mutex<Option<u64>> = Mutex::new(None);
+
+fn one_point_per_process(
+    _stop_watch: &mut StopWatch,
+    _config: &str,
+    work: u64,
+) -> Result<(), anyhow::Error> {
+    RAN.store(true, Ordering::SeqCst);
+    let mut point = POINT.lock().unwrap();
+    match *point {
+        Some(previous) if previous != work => {
+            Err(anyhow!("points {previous} and {work} share a process"))
+        }
+        _ => {
+            *point = Some(work);
+            Ok(())
+        }
+    }
+}
+
+fn noop(_stop_watch: &mut StopWatch, _config: &str, _work: u64) -> Result<(), anyhow::Error> {
+    Ok(())
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add(
+        "points",
+        one_point_per_process,
+        "point",
+        vec![1, 2, 3],
+        3,
+        1,
+    )?;
+    benchmarks.add("teardown", noop, "teardown", vec![1, 2, 3], 3, 1)?;
+    benchmarks.set_series_teardown("teardown", |_config: &&str| {
+        Err(anyhow!("series teardown failed"))
+    })?;
+    benchmarks.add("setup", noop, "setup", vec![1, 2, 3], 3, 1)?;
+    benchmarks.set_series_setup("setup", |_config: &&str| {
+        Err(anyhow!("series setup failed"))
+    })?;
+    benchmarks.set_isolation(Isolation::Point);
+    benchmarks.set_continue_on_error(true);
+    benchmarks.run()?;
+
+    assert!(!RAN.load(Ordering::SeqCst));
+    let summary = benchmarks.summary();
+    let points = &summary.series()["points"];
+    assert_eq!(points.runs().len(), 3);
+    assert!(!points.has_failures());
+
+    // a failed series teardown fails the point of the child, the next points still run
+    let teardown = &summary.series()["teardown"];
+    assert_eq!(teardown.runs().len(), 3);
+    let failed_points: Vec<Option<&String>> = teardown
+        .failures()
+        .iter()
+        .map(|failure| failure.point())
+        .collect();
+    assert_eq!(
+        failed_points,
+        vec![
+            Some(&"1".to_string()),
+            Some(&"2".to_string()),
+            Some(&"3".to_string())
+        ]
+    );
+    assert!(teardown.failures()[0]
+        .message()
+        .contains("series teardown failed"));
+
+    // a failed series setup stops the series
+    let setup = &summary.series()["setup"];
+    assert!(setup.runs().is_empty());
+    assert_eq!(setup.failures().len(), 1);
+    assert_eq!(setup.failures()[0].point(), None);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use std::env;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::failure::FailureKind;
+use benchmark_rs::isolation::Isolation;
+use benchmark_rs::stopwatch::StopWatch;
+
+const PARENT_VAR: &str = "TEST_ISOLATION_PARENT";
+
+fn in_child(_stop_watch: &mut StopWatch, _config: &str, _work: u64) -> Result<(), anyhow::Error> {
+    if env::var(PARENT_VAR)? == process::id().to_string() {
+        Err(anyhow!("benchmark runs in the parent process"))
+    } else {
+        Ok(())
+    }
+}
+
+fn abort_on_two(
+    _stop_watch: &mut StopWatch,
+    _config: &str,
+    work: u64,
+) -> Result<(), anyhow::Error> {
+    if work == 2 {
+        process::abort();
+    }
+    Ok(())
+}
+
+fn hang_on_two(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    if work == 2 {
+        thread::sleep(Duration::from_secs(60));
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    if env::var(PARENT_VAR).is_err() {
+        env::set_var(PARENT_VAR, process::id().to_string());
+    }
+
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("in child", in_child, "child", vec![1, 2, 3], 2, 1)?;
+    benchmarks.add("aborting", abort_on_two, "abort", vec![1, 2, 3], 2, 1)?;
+    benchmarks.add("hanging", hang_on_two, "hang", vec![1, 2, 3], 2, 1)?;
+    benchmarks.set_point_timeout("hanging", Duration::from_millis(500))?;
+    benchmarks.set_isolation(Isolation::Series);
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let in_child = &summary.series()["in child"];
+    assert_eq!(in_child.runs().len(), 3);
+    assert!(!in_child.has_failures());
+
+    for (name, kind) in [
+        ("aborting", FailureKind::Crash),
+        ("hanging", FailureKind::Timeout),
+    ] {
+        let series = &summary.series()[name];
+        let points: Vec<&String> = series.runs().iter().map(|(point, _)| point).collect();
+        assert_eq!(points, vec!["1", "3"], "{name}");
+        assert_eq!(series.failures().len(), 1, "{name}");
+        assert_eq!(series.failures()[0].point(), Some(&"2".to_string()));
+        assert_eq!(series.failures()[0].kind(), kind);
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::isolation::Isolation;
+use benchmark_rs::stopwatch::StopWatch;
+
+const PARENT_VAR: &str = "TEST_ISOLATION_SUITES_PARENT";
+
+fn noop(_stop_watch: &mut StopWatch, _config: &str, _work: u64) -> Result<(), anyhow::Error> {
+    Ok(())
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    if env::var(PARENT_VAR).is_err() {
+        env::set_var(PARENT_VAR, process::id().to_string());
+    }
+    let dir = PathBuf::from("./target/test_isolation_suites").join(env::var(PARENT_VAR)?);
+
+    let mut saved = Benchmarks::new("Saved");
+    saved.add("in process", noop, "noop", vec![1, 2], 2, 0)?;
+    saved.run()?;
+    saved.save_to_json(dir.clone())?;
+    saved.save_to_csv(dir.clone(), true, true)?;
+
+    let mut isolated = Benchmarks::new("Isolated");
+    isolated.add("in child", noop, "noop", vec![1, 2], 2, 0)?;
+    isolated.set_isolation(Isolation::Series);
+    isolated.run()?;
+    assert_eq!(isolated.summary().series()["in child"].runs().len(), 2);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(dir.join("Saved.json"))?)?;
+    assert!(json["series"]["in process"].is_object(), "{json}");
+    assert_eq!(
+        fs::read_to_string(dir.join("in process.csv"))?
+            .lines()
+            .count(),
+        3
+    );
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::summary::Summary;
+
+fn sum(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    let _sum: u64 = (0..work).sum();
+    Ok(())
+}
+
+fn save(
+    dir: &Path,
+    name: &str,
+) -> Result<Benchmarks<&'static str, u64, anyhow::Error>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new(name);
+    benchmarks.add("sum", sum, "sum", vec![10, 100], 3, 1)?;
+    benchmarks.run()?;
+    benchmarks.save_to_json(dir.to_path_buf())?;
+    Ok(benchmarks)
+}
+
+#[test]
+fn test_load() -> Result<(), anyhow::Error> {
+    let dir = PathBuf::from("target/test-load/results");
+    let _ = fs::remove_dir_all(&dir);
+    let benchmarks = save(&dir, "first")?;
+    save(&dir, "second")?;
+    fs::write(dir.join("notes.txt"), "not a summary")?;
+
+    let summary = Summary::load(&dir, "first")?;
+    assert_eq!(summary.name(), "first");
+    assert_eq!(summary.series()["sum"].runs().len(), 2);
+    assert_eq!(
+        Summary::from_json_file(&dir.join("first.json"))?.created_at(),
+        summary.created_at()
+    );
+
+    let summaries = Summary::load_dir(&dir)?;
+    let mut names: Vec<&String> = summaries.keys().collect();
+    names.sort();
+    assert_eq!(names, vec!["first", "second"]);
+
+    let analysis_result = benchmarks.analyze_against_file(&dir.join("first.json"), 1000.0)?;
+    assert_eq!(analysis_result.equal_series().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_load_errors() -> Result<(), anyhow::Error> {
+    let dir = PathBuf::from("target/test-load/errors");
+    let _ = fs::remove_dir_all(&dir);
+    save(&dir, "broken")?;
+    let path = dir.join("broken.json");
+    let json = fs::read_to_string(&path)?.replacen(
+        "\"median_nanos\": ",
+        "\"median_nanos\": \"x\", \"ignored\": ",
+        1,
+    );
+    fs::write(&path, json)?;
+
+    let e = Summary::load(&dir, "broken").unwrap_err();
+    let message = format!("{e:#}");
+    assert!(message.contains("broken.json"), "{message}");
+    assert!(
+        message.contains("series.sum.runs[0][1].median_nanos"),
+        "{message}"
+    );
+
+    let e = Summary::load(&dir, "missing").unwrap_err();
+    assert!(format!("{e:#}").contains("missing.json"));
+
+    let e = Summary::from_json("{\"name\": 1}").unwrap_err();
+    assert!(format!("{e:#}").contains("field: name"));
+    Ok(())
+}
+
+#[test]
+fn test_load_dir_duplicate_suite() -> Result<(), anyhow::Error> {
+    let dir = PathBuf::from("target/test-load/duplicate");
+    let _ = fs::remove_dir_all(&dir);
+    save(&dir, "suite")?;
+    fs::copy(dir.join("suite.json"), dir.join("copy.json"))?;
+
+    let e = Summary::load_dir(&dir).unwrap_err();
+    let message = format!("{e:#}");
+    assert!(message.contains("suite.json"), "{message}");
+    assert!(message.contains("copy.json"), "{message}");
+    Ok(())
+}
+
+#[test]
+fn test_dotted_names_round_trip() -> Result<(), anyhow::Error> {
+    let dir = PathBuf::from("target/test-load/dotted");
+    let _ = fs::remove_dir_all(&dir);
+    let mut benchmarks = Benchmarks::new("suite.v2");
+    benchmarks.add("sum.v2", sum, "sum", vec![10, 100], 3, 1)?;
+    benchmarks.add("sum.v3", sum, "sum", vec![10, 100], 3, 1)?;
+    benchmarks.run()?;
+    benchmarks.save_to_json(dir.clone())?;
+    benchmarks.save_to_csv(dir.clone(), true, true)?;
+
+    let summary = Summary::load(&dir, "suite.v2")?;
+    assert_eq!(summary.name(), "suite.v2");
+    let summaries = Summary::load_dir(&dir)?;
+    assert_eq!(summaries.keys().collect::<Vec<_>>(), vec!["suite.v2"]);
+
+    let loaded = Summary::load_csv_dir(&dir, "suite.v2")?;
+    let mut names: Vec<&String> = loaded.series().keys().collect();
+    names.sort();
+    assert_eq!(names, vec!["sum.v2", "sum.v3"]);
+    assert_eq!(loaded.series()["sum.v2"].runs().len(), 2);
+    Ok(())
+}
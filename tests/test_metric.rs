@@ -0,0 +1,163 @@
+mod common;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::comparison::{compare_runs, ComparisonOptions};
+use benchmark_rs::metric::Metric;
+use common::{sleep_benchmarks, RunSummaryBuilder};
+use serde_json::{json, Value};
+
+#[test]
+fn test_metric_names() -> Result<(), anyhow::Error> {
+    for metric in [
+        Metric::Min,
+        Metric::Median,
+        Metric::Mean,
+        Metric::Percentile(99.9),
+        Metric::Throughput,
+    ] {
+        assert_eq!(Metric::from_name(&metric.name())?, metric);
+    }
+    assert_eq!(Metric::from_name("p99")?, Metric::Percentile(99.0));
+    assert!(Metric::from_name("p101").is_err());
+    assert!(Metric::from_name("max").is_err());
+    assert_eq!(Metric::Throughput.unit(), "ops/s");
+    assert_eq!(Metric::Percentile(50.0).unit(), "ns");
+    Ok(())
+}
+
+#[test]
+fn test_compare_metrics() -> Result<(), anyhow::Error> {
+    // same median, the current run has a slower tail and a faster minimum
+    let previous = RunSummaryBuilder::new("series")
+        .samples(&[100.0, 100.0, 100.0, 100.0, 110.0])
+        .build();
+    let current = RunSummaryBuilder::new("series")
+        .samples(&[50.0, 100.0, 100.0, 100.0, 200.0])
+        .build();
+    let mut options = ComparisonOptions::new(5.0);
+    let compare = |options: &ComparisonOptions| compare_runs("1", &current, &previous, options);
+
+    let comparison = compare(&options)?;
+    assert!(matches!(comparison, BenchmarkComparison::Equal { .. }));
+    assert_eq!(comparison.metric(), Metric::Median);
+
+    options.set_metric(Metric::Min)?;
+    assert!(matches!(
+        compare(&options)?,
+        BenchmarkComparison::Less { previous, current, .. } if previous == 100.0 && current == 50.0
+    ));
+
+    options.set_metric(Metric::Mean)?;
+    assert!(matches!(
+        compare(&options)?,
+        BenchmarkComparison::Greater { .. }
+    ));
+
+    options.set_metric(Metric::Percentile(90.0))?;
+    let comparison = compare(&options)?;
+    assert!(matches!(comparison, BenchmarkComparison::Greater { .. }));
+    assert!(comparison.is_regression());
+    assert_eq!(comparison.metric(), Metric::Percentile(90.0));
+
+    // lower throughput is a regression
+    let slower = RunSummaryBuilder::new("series")
+        .samples(&[200.0, 200.0, 200.0])
+        .build();
+    options.set_metric(Metric::Throughput)?;
+    let comparison = compare_runs("1", &slower, &previous, &options)?;
+    assert!(matches!(
+        comparison,
+        BenchmarkComparison::Less { previous, current, .. } if previous == 1e7 && current == 5e6
+    ));
+    assert!(comparison.is_regression());
+
+    assert!(options.set_metric(Metric::Percentile(-1.0)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_compare_metric_without_samples() -> Result<(), anyhow::Error> {
+    // the statistics of the previous run are compared when only the current run has samples
+    let previous = RunSummaryBuilder::new("series").median(100).build();
+    let current = RunSummaryBuilder::new("series")
+        .samples(&[50.0, 60.0, 300.0, 300.0])
+        .build();
+    assert_eq!(current.median_nanos(), 300);
+    let options = ComparisonOptions::new(5.0);
+    assert!(matches!(
+        compare_runs("1", &current, &previous, &options)?,
+        BenchmarkComparison::Greater { previous, current, .. } if previous == 100.0 && current == 300.0
+    ));
+    assert!(matches!(
+        compare_runs("1", &previous, &current, &options)?,
+        BenchmarkComparison::Less { previous, current, .. } if previous == 300.0 && current == 100.0
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_analyze_metric() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(5, 0)?;
+    benchmarks.set_percentiles(&[99.0])?;
+    benchmarks.set_comparison_metric(Metric::Percentile(99.0))?;
+    benchmarks.run()?;
+    let previous = benchmarks.summary_as_json();
+    benchmarks.run()?;
+    let analysis_result = benchmarks.analyze(Some(previous.clone()), 1000.0)?;
+    assert_eq!(analysis_result.metric(), Metric::Percentile(99.0));
+    // the tail latency of sleeping is noisy, the points may be equal or divergent
+    let comparisons = analysis_result
+        .equal_series()
+        .values()
+        .chain(analysis_result.divergent_series().values())
+        .flat_map(|comparisons| comparisons.values());
+    assert_eq!(comparisons.clone().count(), 2);
+    for comparison in comparisons {
+        assert_eq!(comparison.metric(), Metric::Percentile(99.0));
+    }
+
+    // points without the percentile are reported as not comparable
+    benchmarks.set_comparison_metric(Metric::Percentile(50.0))?;
+    let analysis_result = benchmarks.analyze(Some(previous), 1000.0)?;
+    assert!(analysis_result.equal_series().is_empty());
+    assert!(analysis_result.divergent_series().is_empty());
+    assert_eq!(
+        analysis_result.incomparable_points()["sleep"],
+        vec!["100".to_string(), "200".to_string()]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_compare_mean_without_mean() -> Result<(), anyhow::Error> {
+    let previous = RunSummaryBuilder::new("series").median(100).build();
+    let current = RunSummaryBuilder::new("series")
+        .median(100)
+        .set("mean_nanos", json!(100.0))
+        .build();
+    let mut options = ComparisonOptions::new(5.0);
+    options.set_metric(Metric::Mean)?;
+    assert!(compare_runs("1", &current, &previous, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_analyze_mean_of_old_summary() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(5, 0)?;
+    benchmarks.set_comparison_metric(Metric::Mean)?;
+    benchmarks.run()?;
+    let mut previous: Value = serde_json::from_str(&benchmarks.summary_as_json())?;
+    // summaries saved before the mean was recorded have no mean
+    for point_run in previous["series"]["sleep"]["runs"].as_array_mut().unwrap() {
+        point_run[1].as_object_mut().unwrap().remove("mean_nanos");
+    }
+    benchmarks.run()?;
+    let analysis_result = benchmarks.analyze(Some(previous.to_string()), 1000.0)?;
+    assert!(analysis_result.equal_series().is_empty());
+    assert!(analysis_result.divergent_series().is_empty());
+    assert_eq!(
+        analysis_result.incomparable_points()["sleep"],
+        vec!["100".to_string(), "200".to_string()]
+    );
+    Ok(())
+}
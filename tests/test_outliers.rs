@@ -0,0 +1,110 @@
+mod common;
+
+use std::thread;
+use std::time::Duration;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::{compare_runs, ComparisonOptions, SignificanceTest};
+use benchmark_rs::metric::Metric;
+use benchmark_rs::outliers::OutlierPolicy;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::summary::Summary;
+use common::{sleep, RunSummaryBuilder};
+use serde_json::json;
+
+const HICCUP: Duration = Duration::from_millis(30);
+
+fn benchmarks() -> Result<Benchmarks<&'static str, u64, anyhow::Error>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    let mut iteration = 0;
+    benchmarks.add_closure(
+        "hiccup",
+        move |stop_watch: &mut StopWatch, config: &str, work: u64| {
+            iteration += 1;
+            if iteration == 10 {
+                thread::sleep(HICCUP);
+                Ok(())
+            } else {
+                sleep(stop_watch, config, work)
+            }
+        },
+        "hiccup",
+        vec![1000],
+        20,
+        0,
+    )?;
+    Ok(benchmarks)
+}
+
+#[test]
+fn test_outliers_included() -> Result<(), anyhow::Error> {
+    let mut benchmarks = benchmarks()?;
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    let (_point, run) = &summary.series()["hiccup"].runs()[0];
+    assert_eq!(run.outliers().policy(), OutlierPolicy::Include);
+    assert!(run.outliers().severe() >= 1);
+    assert_eq!(run.outliers().excluded(), 0);
+    assert!(run.max_nanos() >= HICCUP.as_nanos() as u64);
+    Ok(())
+}
+
+#[test]
+fn test_outliers_excluded() -> Result<(), anyhow::Error> {
+    let mut benchmarks = benchmarks()?;
+    benchmarks.set_outlier_policy("hiccup", OutlierPolicy::ExcludeSevere)?;
+    assert!(benchmarks
+        .set_outlier_policy("missing", OutlierPolicy::ExcludeAll)
+        .is_err());
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    let (_point, run) = &summary.series()["hiccup"].runs()[0];
+    assert_eq!(run.outliers().policy(), OutlierPolicy::ExcludeSevere);
+    assert!(run.outliers().severe() >= 1);
+    assert_eq!(run.outliers().excluded(), run.outliers().severe());
+    assert_eq!(run.repeat(), 20);
+    assert!(run.max_nanos() < HICCUP.as_nanos() as u64);
+
+    let loaded = Summary::from_json(&benchmarks.summary_as_json())?;
+    let (_point, loaded_run) = &loaded.series()["hiccup"].runs()[0];
+    assert_eq!(loaded_run.outliers(), run.outliers());
+    Ok(())
+}
+
+#[test]
+fn test_compare_without_outliers() -> Result<(), anyhow::Error> {
+    let samples: Vec<f64> = (0..20).map(|i| 1000.0 + (i * 7 % 20) as f64).collect();
+    let previous = RunSummaryBuilder::new("hiccup").samples(&samples).build();
+    let mut with_hiccup = samples.clone();
+    with_hiccup[10] = HICCUP.as_nanos() as f64;
+    let current = |policy: &str| {
+        RunSummaryBuilder::new("hiccup")
+            .samples(&with_hiccup)
+            .set(
+                "outliers",
+                json!({"policy": policy, "mild": 0, "severe": 1}),
+            )
+            .build()
+    };
+
+    let mut options = ComparisonOptions::new(5.0);
+    options.set_metric(Metric::Mean)?;
+    assert!(matches!(
+        compare_runs("1", &current("Include"), &previous, &options)?,
+        BenchmarkComparison::Greater { .. }
+    ));
+    assert!(matches!(
+        compare_runs("1", &current("ExcludeSevere"), &previous, &options)?,
+        BenchmarkComparison::Equal { .. }
+    ));
+
+    // the excluded sample does not reach the significance test either
+    options.set_metric(Metric::Median)?;
+    options.set_significance_test(Some(SignificanceTest::Welch), 0.05)?;
+    let included = compare_runs("1", &current("Include"), &previous, &options)?;
+    let excluded = compare_runs("1", &current("ExcludeSevere"), &previous, &options)?;
+    assert_ne!(included.p_value(), excluded.p_value());
+    assert!(excluded.p_value().unwrap() >= 0.05);
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::threading::Threaded;
+
+fn sleep(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    thread::sleep(Duration::from_millis(work));
+    Ok(())
+}
+
+fn fail(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    Err(anyhow!("failed on {work}"))
+}
+
+fn sleeping_benchmarks(
+) -> Result<Benchmarks<&'static str, u64, anyhow::Error, Threaded>, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new_threaded("Test");
+    for i in 0..4 {
+        benchmarks.add(&format!("sleep {i}"), sleep, "sleep", vec![10, 20], 5, 0)?;
+    }
+    Ok(benchmarks)
+}
+
+#[test]
+fn test_run_parallel() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleeping_benchmarks()?;
+    let started = Instant::now();
+    benchmarks.run_parallel(4)?;
+    assert!(started.elapsed() < Duration::from_millis(4 * 150));
+
+    let summary = benchmarks.summary();
+    assert_eq!(summary.workers(), 4);
+    assert_eq!(summary.series().len(), 4);
+    for series_summary in summary.series().values() {
+        assert_eq!(series_summary.runs().len(), 2);
+    }
+    let json: serde_json::Value = serde_json::from_str(&benchmarks.summary_as_json())?;
+    assert_eq!(json["workers"], 4);
+    Ok(())
+}
+
+#[test]
+fn test_parallel_against_serial_baseline() -> Result<(), anyhow::Error> {
+    let mut serial = sleeping_benchmarks()?;
+    serial.run()?;
+    assert_eq!(serial.summary().workers(), 1);
+
+    let mut parallel = sleeping_benchmarks()?;
+    parallel.run_parallel(2)?;
+    assert!(parallel
+        .analyze(Some(serial.summary_as_json()), 5.0)
+        .is_err());
+    assert!(parallel
+        .analyze(Some(parallel.summary_as_json()), 5.0)
+        .is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_parallel_stop_on_error() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleeping_benchmarks()?;
+    benchmarks.add("failing", fail, "fail", vec![1], 1, 0)?;
+    assert!(benchmarks.run_parallel(2).is_err());
+    assert!(sleeping_benchmarks()?.run_parallel(0).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_local_closures() -> Result<(), anyhow::Error> {
+    let invocations = Rc::new(RefCell::new(0));
+    let teardowns = Rc::new(RefCell::new(0));
+    let mut benchmarks = Benchmarks::new("Test");
+    let counter = invocations.clone();
+    benchmarks.add_closure(
+        "count",
+        move |_stop_watch: &mut StopWatch, _config: &str, _work: u64| {
+            *counter.borrow_mut() += 1;
+            Ok::<(), anyhow::Error>(())
+        },
+        "count",
+        vec![1, 2],
+        3,
+        1,
+    )?;
+    let counter = teardowns.clone();
+    benchmarks.set_point_teardown("count", move |_config: &&str, _work: &u64| {
+        *counter.borrow_mut() += 1;
+        Ok(())
+    })?;
+    benchmarks.run()?;
+    assert_eq!(*invocations.borrow(), 8);
+    assert_eq!(*teardowns.borrow(), 8);
+    Ok(())
+}
+
+#[test]
+fn test_threaded_closures() -> Result<(), anyhow::Error> {
+    let invocations = Arc::new(AtomicUsize::new(0));
+    let mut benchmarks = sleeping_benchmarks()?;
+    let counter = invocations.clone();
+    benchmarks.add_with_setup(
+        "count",
+        |_config: &&str, work: &u64| Ok(*work),
+        move |_stop_watch: &mut StopWatch, _config: &str, _input: u64| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok::<(), anyhow::Error>(())
+        },
+        "count",
+        vec![1, 2],
+        3,
+        1,
+    )?;
+    benchmarks.run_parallel(2)?;
+    assert_eq!(invocations.load(Ordering::SeqCst), 8);
+    Ok(())
+}
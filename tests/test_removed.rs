@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::{compare_points, compare_summaries, ComparisonOptions};
+use benchmark_rs::filter::{Filter, NamePattern};
+use benchmark_rs::harness::HarnessArgs;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::summary::Summary;
+
+fn noop(_stop_watch: &mut StopWatch, _config: &str, _work: usize) -> Result<(), anyhow::Error> {
+    Ok(())
+}
+
+/// Run series at the given workload points, selected by `filter`
+fn summary(series: &[(&str, Vec<usize>)], filter: Filter) -> Result<Summary, anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    for (name, points) in series {
+        benchmarks.add(name, noop, "noop", points.clone(), 2, 0)?;
+    }
+    benchmarks.set_filter(filter);
+    benchmarks.run()?;
+    Ok(benchmarks.summary())
+}
+
+#[test]
+fn test_removed_series() -> Result<(), anyhow::Error> {
+    let previous = summary(
+        &[
+            ("kept", vec![1]),
+            ("removed", vec![1]),
+            ("skipped", vec![1]),
+        ],
+        Filter::new(),
+    )?;
+    let mut filter = Filter::new();
+    filter.add_name(NamePattern::substring("kept"));
+    let current = summary(&[("kept", vec![1]), ("skipped", vec![1])], filter)?;
+
+    let analysis_result = compare_summaries(&current, &previous, &ComparisonOptions::new(1000.0))?;
+    assert_eq!(
+        analysis_result.removed_series(),
+        &HashSet::from(["removed".to_string()])
+    );
+    assert!(analysis_result.new_series().is_empty());
+    assert!(analysis_result.has_removed());
+    assert!(!analysis_result.has_regressions());
+
+    let analysis_result = compare_summaries(&previous, &current, &ComparisonOptions::new(1000.0))?;
+    assert!(analysis_result.removed_series().is_empty());
+    assert!(!analysis_result.has_removed());
+    Ok(())
+}
+
+#[test]
+fn test_removed_points() -> Result<(), anyhow::Error> {
+    let previous = summary(&[("series", vec![1, 2, 3])], Filter::new())?;
+    let current = summary(&[("series", vec![2, 3, 4, 5])], Filter::new())?;
+
+    let (new_points, removed_points) = compare_points(
+        current.series().get("series").unwrap(),
+        previous.series().get("series").unwrap(),
+    );
+    assert_eq!(new_points, vec!["4", "5"]);
+    assert_eq!(removed_points, vec!["1"]);
+
+    let analysis_result = compare_summaries(&current, &previous, &ComparisonOptions::new(1000.0))?;
+    assert_eq!(
+        analysis_result.new_points().get("series"),
+        Some(&vec!["4".to_string(), "5".to_string()])
+    );
+    assert_eq!(
+        analysis_result.removed_points().get("series"),
+        Some(&vec!["1".to_string()])
+    );
+    assert!(analysis_result.removed_series().is_empty());
+    assert!(analysis_result.has_removed());
+
+    let comparisons = analysis_result
+        .equal_series()
+        .get("series")
+        .or_else(|| analysis_result.divergent_series().get("series"))
+        .unwrap();
+    let mut compared: Vec<&String> = comparisons.keys().collect();
+    compared.sort();
+    assert_eq!(compared, vec!["2", "3"]);
+    Ok(())
+}
+
+#[test]
+fn test_same_points() -> Result<(), anyhow::Error> {
+    let previous = summary(&[("series", vec![1, 2])], Filter::new())?;
+    let current = summary(&[("series", vec![1, 2])], Filter::new())?;
+
+    let analysis_result = compare_summaries(&current, &previous, &ComparisonOptions::new(1000.0))?;
+    assert!(analysis_result.new_points().is_empty());
+    assert!(analysis_result.removed_points().is_empty());
+    assert!(!analysis_result.has_removed());
+    Ok(())
+}
+
+#[test]
+fn test_failed_series_setup() -> Result<(), anyhow::Error> {
+    let previous = summary(&[("series", vec![1, 2])], Filter::new())?;
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("series", noop, "noop", vec![1, 2], 2, 0)?;
+    benchmarks.set_series_setup("series", |_config: &&str| {
+        Err(anyhow::anyhow!("setup failed"))
+    })?;
+    benchmarks.set_continue_on_error(true);
+    benchmarks.run()?;
+    let current = benchmarks.summary();
+
+    let analysis_result = compare_summaries(&current, &previous, &ComparisonOptions::new(1000.0))?;
+    assert!(analysis_result.removed_points().is_empty());
+    assert!(analysis_result.new_points().is_empty());
+    assert!(!analysis_result.has_removed());
+    assert!(analysis_result.failed_series().contains_key("series"));
+
+    let analysis_result = compare_summaries(&previous, &current, &ComparisonOptions::new(1000.0))?;
+    assert!(analysis_result.new_points().is_empty());
+    assert!(!analysis_result.has_removed());
+    Ok(())
+}
+
+#[test]
+fn test_fail_on_removed_arg() -> Result<(), anyhow::Error> {
+    let args = HarnessArgs::parse(vec!["--fail-on-removed".to_string()])?;
+    assert!(args.fail_on_removed());
+    let args = HarnessArgs::parse(Vec::<String>::new())?;
+    assert!(!args.fail_on_removed());
+    Ok(())
+}
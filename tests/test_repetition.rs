@@ -0,0 +1,113 @@
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::repetition::Repetition;
+use benchmark_rs::stopwatch::StopWatch;
+use std::thread::sleep;
+use std::time::Duration;
+
+fn bench_sleep(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    sleep(Duration::from_millis(work));
+    Ok(())
+}
+
+fn samples(benchmarks: &Benchmarks<&str, u64, anyhow::Error>, name: &str) -> usize {
+    benchmarks.summary().series()[name].runs()[0].1.repeat()
+}
+
+#[test]
+fn test_adaptive_precision() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("precise", bench_sleep, "sleep", vec![2], 1, 0)?;
+    benchmarks.set_repetition(
+        "precise",
+        Repetition::adaptive(50.0, Duration::from_secs(10), 5, 1000),
+    )?;
+    benchmarks.run()?;
+
+    let samples = samples(&benchmarks, "precise");
+    assert!((5..1000).contains(&samples), "samples: {samples}");
+    Ok(())
+}
+
+#[test]
+fn test_adaptive_time_budget() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("budget", bench_sleep, "sleep", vec![5], 1, 0)?;
+    benchmarks.set_repetition(
+        "budget",
+        Repetition::adaptive(1e-9, Duration::from_millis(50), 2, 1000),
+    )?;
+    benchmarks.run()?;
+
+    let samples = samples(&benchmarks, "budget");
+    assert!((2..=11).contains(&samples), "samples: {samples}");
+    Ok(())
+}
+
+#[test]
+fn test_adaptive_max_repeat() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("capped", bench_sleep, "sleep", vec![0], 1, 0)?;
+    benchmarks.set_repetition(
+        "capped",
+        Repetition::adaptive(1e-9, Duration::from_secs(10), 2, 7),
+    )?;
+    benchmarks.run()?;
+
+    assert_eq!(samples(&benchmarks, "capped"), 7);
+    assert_eq!(
+        benchmarks.summary().series()["capped"].runs()[0].1.repeat(),
+        7
+    );
+    let csv = &benchmarks.summary_as_csv(true, false)["capped"];
+    assert_eq!(csv[1].split(',').nth(2), Some("7"));
+    Ok(())
+}
+
+#[test]
+fn test_adaptive_invalid_bounds() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("invalid", bench_sleep, "sleep", vec![0], 1, 0)?;
+    assert!(benchmarks
+        .set_repetition(
+            "invalid",
+            Repetition::adaptive(5.0, Duration::from_secs(1), 0, 10)
+        )
+        .is_err());
+    assert!(benchmarks
+        .set_repetition(
+            "invalid",
+            Repetition::adaptive(5.0, Duration::from_secs(1), 10, 5)
+        )
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_default_repetition() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("before", bench_sleep, "sleep", vec![0], 1, 0)?;
+    assert!(benchmarks
+        .set_default_repetition(Repetition::adaptive(5.0, Duration::from_secs(1), 0, 10))
+        .is_err());
+    benchmarks.set_default_repetition(Repetition::adaptive(1e-9, Duration::from_secs(10), 2, 6))?;
+    // series added after the default was set are repeated by it as well
+    benchmarks.add("after", bench_sleep, "sleep", vec![0], 1, 0)?;
+    benchmarks.add("own", bench_sleep, "sleep", vec![0], 1, 0)?;
+    benchmarks.set_repetition("own", Repetition::Fixed)?;
+    benchmarks.run()?;
+
+    assert_eq!(samples(&benchmarks, "before"), 6);
+    assert_eq!(samples(&benchmarks, "after"), 6);
+    assert_eq!(samples(&benchmarks, "own"), 1);
+    Ok(())
+}
+
+#[test]
+fn test_fixed_samples() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("fixed", bench_sleep, "sleep", vec![0], 4, 1)?;
+    benchmarks.run()?;
+
+    assert_eq!(samples(&benchmarks, "fixed"), 4);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::comparison::{compare_runs, ComparisonOptions};
+use benchmark_rs::summary::Summary;
+use common::sleep_benchmarks;
+
+#[test]
+fn test_samples_not_retained() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(5, 2)?;
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    for (_point, run) in summary.series()["sleep"].runs() {
+        assert!(run.raw_samples().is_empty());
+    }
+    assert!(!benchmarks.summary_as_json().contains("raw_samples"));
+    Ok(())
+}
+
+#[test]
+fn test_retain_samples() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(5, 2)?;
+    benchmarks.set_retain_samples(true);
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    for (_point, run) in summary.series()["sleep"].runs() {
+        let iterations: Vec<usize> = run.raw_samples().iter().map(|s| s.iteration()).collect();
+        assert_eq!(iterations, vec![2, 3, 4, 5, 6]);
+        for sample in run.raw_samples() {
+            assert!(run.min_nanos() as f64 <= sample.nanos().ceil());
+            assert!(sample.nanos().floor() <= run.max_nanos() as f64);
+        }
+    }
+
+    let loaded = Summary::from_json(&benchmarks.summary_as_json())?;
+    assert_eq!(
+        loaded.series()["sleep"].runs()[0].1.raw_samples(),
+        summary.series()["sleep"].runs()[0].1.raw_samples()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_analyze_with_samples() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(5, 2)?;
+    benchmarks.set_retain_samples(true);
+    benchmarks.run()?;
+    let previous = benchmarks.summary_as_json();
+    benchmarks.run()?;
+    let analysis_result = benchmarks.analyze(Some(previous), 1000.0)?;
+    assert_eq!(analysis_result.equal_series().len(), 1);
+
+    let summary = benchmarks.summary();
+    let (point, run) = &summary.series()["sleep"].runs()[0];
+    match compare_runs(point, run, run, &ComparisonOptions::new(0.0))? {
+        BenchmarkComparison::Equal { change, .. } => assert_eq!(change, 0.0),
+        comparison => panic!("unexpected comparison: {:?}", comparison),
+    }
+    Ok(())
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::{compare_runs, ComparisonOptions, SignificanceTest};
+use benchmark_rs::stopwatch::StopWatch;
+use common::RunSummaryBuilder;
+
+fn samples(offset: f64) -> Vec<f64> {
+    (0..20)
+        .map(|i| 1000.0 + offset + (i * 7 % 20) as f64 * 10.0)
+        .collect()
+}
+
+#[test]
+fn test_significance() -> Result<(), anyhow::Error> {
+    let previous = RunSummaryBuilder::new("series")
+        .samples(&samples(0.0))
+        .build();
+    let shifted = RunSummaryBuilder::new("series")
+        .samples(&samples(20.0))
+        .build();
+    let regressed = RunSummaryBuilder::new("series")
+        .samples(&samples(500.0))
+        .build();
+
+    let mut options = ComparisonOptions::new(1.0);
+    assert!(matches!(
+        compare_runs("1", &shifted, &previous, &options)?,
+        BenchmarkComparison::Greater { p_value: None, .. }
+    ));
+
+    for significance_test in [SignificanceTest::MannWhitney, SignificanceTest::Welch] {
+        options.set_significance_test(Some(significance_test), 0.05)?;
+        let comparison = compare_runs("1", &shifted, &previous, &options)?;
+        assert!(matches!(comparison, BenchmarkComparison::Equal { .. }));
+        assert!(comparison.p_value().unwrap() >= 0.05);
+        assert!(comparison.effect_size().unwrap() > 0.0);
+
+        let comparison = compare_runs("1", &regressed, &previous, &options)?;
+        assert!(matches!(comparison, BenchmarkComparison::Greater { .. }));
+        assert!(comparison.p_value().unwrap() < 0.001);
+        assert!(comparison.effect_size().unwrap() > 0.0);
+
+        let comparison = compare_runs("1", &previous, &regressed, &options)?;
+        assert!(matches!(comparison, BenchmarkComparison::Less { .. }));
+        assert!(comparison.effect_size().unwrap() < 0.0);
+    }
+
+    assert!(options
+        .set_significance_test(Some(SignificanceTest::Welch), 1.0)
+        .is_err());
+    assert_eq!(
+        SignificanceTest::from_name("mann-whitney"),
+        Some(SignificanceTest::MannWhitney)
+    );
+    assert_eq!(SignificanceTest::Welch.name(), "welch");
+    Ok(())
+}
+
+#[test]
+fn test_analyze_with_significance() -> Result<(), anyhow::Error> {
+    let mut benchmarks: Benchmarks<&str, u64, anyhow::Error> = Benchmarks::new("Test");
+    benchmarks.add(
+        "sum",
+        |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            let _sum: u64 = (0..work).sum();
+            Ok(())
+        },
+        "sum",
+        vec![10, 100],
+        10,
+        0,
+    )?;
+    assert!(benchmarks
+        .set_significance_test(Some(SignificanceTest::MannWhitney), 0.0)
+        .is_err());
+    benchmarks.set_significance_test(Some(SignificanceTest::MannWhitney), 0.05)?;
+    benchmarks.set_retain_samples(true);
+    benchmarks.run()?;
+    let previous = benchmarks.summary_as_json();
+    benchmarks.run()?;
+    let analysis_result = benchmarks.analyze(Some(previous), 5.0)?;
+    for comparisons in analysis_result
+        .equal_series()
+        .values()
+        .chain(analysis_result.divergent_series().values())
+    {
+        for comparison in comparisons.values() {
+            assert!(comparison.p_value().is_some());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use benchmark_rs::series_summary::SeriesSummary;
+use benchmark_rs::summary::Summary;
+use common::sleep_benchmarks;
+
+#[test]
+fn test_statistics() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(20, 1)?;
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    for (_point, run) in summary.series()["sleep"].runs() {
+        let percentiles: Vec<f64> = run
+            .percentiles()
+            .iter()
+            .map(|percentile| percentile.percentile())
+            .collect();
+        assert_eq!(percentiles, vec![5.0, 25.0, 75.0, 90.0, 95.0, 99.0]);
+        let nanos: Vec<u64> = run
+            .percentiles()
+            .iter()
+            .map(|p| p.nanos().round() as u64)
+            .collect();
+        assert!(nanos.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(run.min_nanos() <= nanos[0] && nanos[5] <= run.max_nanos());
+        let mean = run.mean_nanos().round() as u64;
+        assert!(run.min_nanos() <= mean && mean <= run.max_nanos());
+        let range = (run.max_nanos() - run.min_nanos() + 1) as f64;
+        assert!(run.iqr_nanos() <= range);
+        assert!(run.mad_nanos() <= range);
+        assert!(run.cv().unwrap() >= 0.0);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_set_percentiles() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(20, 1)?;
+    assert!(benchmarks.set_percentiles(&[50.0, 101.0]).is_err());
+    benchmarks.set_percentiles(&[50.0, 99.9])?;
+    benchmarks.run()?;
+    let summary = benchmarks.summary();
+    let (_point, run) = &summary.series()["sleep"].runs()[0];
+    let labels: Vec<String> = run.percentiles().iter().map(|p| p.label()).collect();
+    assert_eq!(labels, vec!["p50", "p99.9"]);
+    assert_eq!(
+        run.percentiles()[0].nanos().round() as u64,
+        run.median_nanos()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_csv_statistics() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(20, 1)?;
+    benchmarks.set_percentiles(&[90.0])?;
+    benchmarks.run()?;
+    assert_eq!(
+        benchmarks.summary_as_csv(true, false)["sleep"][0],
+        "point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec,status"
+    );
+
+    benchmarks.set_csv_statistics(true);
+    let csv = &benchmarks.summary_as_csv(true, true)["sleep"];
+    assert_eq!(
+        csv[0],
+        "point,ramp_up,repeat,min_sec,max_sec,median_sec,std_dev_sec,\
+         mean_sec,p90_sec,iqr_sec,mad_sec,cv,status,,configuration: sleep"
+    );
+    assert_eq!(csv[1].split(',').count(), 13);
+
+    let series = SeriesSummary::from_csv("sleep", &csv.join("\n"))?;
+    assert_eq!(series.config(), "sleep");
+    assert_eq!(series.runs().len(), 2);
+    assert_eq!(
+        series.runs()[1].1.median_nanos(),
+        benchmarks.summary().series()["sleep"].runs()[1]
+            .1
+            .median_nanos()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_older_summary() -> Result<(), anyhow::Error> {
+    let mut benchmarks = sleep_benchmarks(20, 1)?;
+    benchmarks.run()?;
+    let mut json: serde_json::Value = serde_json::from_str(&benchmarks.summary_as_json())?;
+    for run in json["series"]["sleep"]["runs"].as_array_mut().unwrap() {
+        let run = run[1].as_object_mut().unwrap();
+        for field in ["mean_nanos", "percentiles", "iqr_nanos", "mad_nanos", "cv"] {
+            run.remove(field);
+        }
+    }
+    let summary = Summary::from_json(&json.to_string())?;
+    let (_point, run) = &summary.series()["sleep"].runs()[0];
+    assert_eq!(run.mean_nanos(), 0.0);
+    assert!(run.percentiles().is_empty());
+    assert_eq!(run.cv(), None);
+    Ok(())
+}
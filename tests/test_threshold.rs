@@ -0,0 +1,95 @@
+mod common;
+
+use benchmark_rs::benchmark_comparison::BenchmarkComparison;
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::comparison::{compare_runs, ComparisonOptions};
+use benchmark_rs::filter::NamePattern;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::threshold::{Threshold, ThresholdPolicy};
+use common::RunSummaryBuilder;
+
+fn compare(
+    series: &str,
+    point: &str,
+    current: u64,
+    previous: u64,
+    options: &ComparisonOptions,
+) -> &'static str {
+    match compare_runs(
+        point,
+        &RunSummaryBuilder::new(series).median(current).build(),
+        &RunSummaryBuilder::new(series).median(previous).build(),
+        options,
+    )
+    .unwrap()
+    {
+        BenchmarkComparison::Less { .. } => "less",
+        BenchmarkComparison::Equal { .. } => "equal",
+        BenchmarkComparison::Greater { .. } => "greater",
+    }
+}
+
+#[test]
+fn test_threshold_policy() -> Result<(), anyhow::Error> {
+    let mut threshold_policy = ThresholdPolicy::new();
+    threshold_policy.add_series(NamePattern::substring("io"), Threshold::percent(20.0));
+    threshold_policy.add_series(NamePattern::regex("^cpu")?, Threshold::new(2.0, 10.0, 0));
+    threshold_policy.add_point(NamePattern::glob("*"), "1", Threshold::new(1.0, 1.0, 100));
+
+    let mut options = ComparisonOptions::new(5.0);
+    options.set_threshold_policy(threshold_policy);
+
+    // default threshold
+    assert_eq!(compare("sum", "1000", 10800, 10000, &options), "greater");
+    assert_eq!(compare("sum", "1000", 10400, 10000, &options), "equal");
+    // series threshold
+    assert_eq!(compare("io read", "1000", 11500, 10000, &options), "equal");
+    assert_eq!(
+        compare("io read", "1000", 12500, 10000, &options),
+        "greater"
+    );
+    // separate thresholds for regressions and improvements
+    assert_eq!(
+        compare("cpu sum", "1000", 10300, 10000, &options),
+        "greater"
+    );
+    assert_eq!(compare("cpu sum", "1000", 9200, 10000, &options), "equal");
+    assert_eq!(compare("cpu sum", "1000", 8800, 10000, &options), "less");
+    // point threshold with an absolute floor takes precedence over the series threshold
+    assert_eq!(compare("io read", "1", 1090, 1000, &options), "equal");
+    assert_eq!(compare("io read", "1", 1150, 1000, &options), "greater");
+    assert_eq!(
+        options.threshold_of("io read", "1"),
+        Threshold::new(1.0, 1.0, 100)
+    );
+    assert_eq!(options.threshold_of("sum", "1000"), Threshold::percent(5.0));
+    Ok(())
+}
+
+#[test]
+fn test_analyze_with_threshold_policy() -> Result<(), anyhow::Error> {
+    let mut benchmarks: Benchmarks<&str, u64, anyhow::Error> = Benchmarks::new("Test");
+    benchmarks.add(
+        "sum",
+        |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            let _sum: u64 = (0..work).sum();
+            Ok(())
+        },
+        "sum",
+        vec![10, 100],
+        3,
+        0,
+    )?;
+    let mut threshold_policy = ThresholdPolicy::new();
+    threshold_policy.add_series(
+        NamePattern::substring("sum"),
+        Threshold::new(1000.0, 1000.0, u64::MAX),
+    );
+    benchmarks.set_threshold_policy(threshold_policy);
+    benchmarks.run()?;
+    let previous = benchmarks.summary_as_json();
+    benchmarks.run()?;
+    let analysis_result = benchmarks.analyze(Some(previous), 0.0)?;
+    assert_eq!(analysis_result.equal_series().len(), 1);
+    Ok(())
+}
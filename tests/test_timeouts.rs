@@ -0,0 +1,192 @@
+use benchmark_rs::benchmarks::Benchmarks;
+use benchmark_rs::failure::FailureKind;
+use benchmark_rs::stopwatch::StopWatch;
+use benchmark_rs::threading::Threaded;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+fn sleep_on_two(
+    _stop_watch: &mut StopWatch,
+    _config: &str,
+    work: u64,
+) -> Result<(), anyhow::Error> {
+    if work == 2 {
+        thread::sleep(Duration::from_millis(20));
+    }
+    Ok(())
+}
+
+fn hang_on_two(_stop_watch: &mut StopWatch, _config: &str, work: u64) -> Result<(), anyhow::Error> {
+    if work == 2 {
+        loop {
+            thread::park();
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_iteration_timeout() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("sleeping", sleep_on_two, "sleep", vec![1, 2, 3], 5, 0)?;
+    benchmarks.set_iteration_timeout("sleeping", Duration::from_millis(10))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let sleeping = &summary.series()["sleeping"];
+    let points: Vec<&String> = sleeping.runs().iter().map(|(point, _)| point).collect();
+    assert_eq!(points, vec!["1", "3"]);
+    assert_eq!(sleeping.failures().len(), 1);
+    let failure = &sleeping.failures()[0];
+    assert_eq!(failure.point(), Some(&"2".to_string()));
+    assert_eq!(failure.iteration(), 0);
+    assert_eq!(failure.kind(), FailureKind::Timeout);
+    assert_eq!(
+        &benchmarks.summary_as_csv(true, false)["sleeping"][3],
+        "2,,,,,,,timeout"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_point_timeout() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new("Test");
+    benchmarks.add("sleeping", sleep_on_two, "sleep", vec![1, 2, 3], 5, 0)?;
+    benchmarks.set_point_timeout("sleeping", Duration::from_millis(50))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let sleeping = &summary.series()["sleeping"];
+    assert_eq!(sleeping.runs().len(), 2);
+    let failure = &sleeping.failures()[0];
+    assert_eq!(failure.point(), Some(&"2".to_string()));
+    assert!(failure.iteration() > 0 && failure.iteration() < 5);
+    assert_eq!(failure.kind(), FailureKind::Timeout);
+    Ok(())
+}
+
+#[test]
+fn test_timeout_unknown_series() {
+    let mut benchmarks: Benchmarks<&str, u64, anyhow::Error> = Benchmarks::new("Test");
+    assert!(benchmarks
+        .set_iteration_timeout("missing", Duration::from_millis(10))
+        .is_err());
+}
+
+#[test]
+fn test_hung_function() -> Result<(), anyhow::Error> {
+    let mut benchmarks: Benchmarks<&str, u64, anyhow::Error, Threaded> =
+        Benchmarks::new_threaded("Test");
+    benchmarks.add("hanging", hang_on_two, "hang", vec![1, 2, 3], 5, 0)?;
+    benchmarks.set_iteration_timeout("hanging", Duration::from_millis(50))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let hanging = &summary.series()["hanging"];
+    let points: Vec<&String> = hanging.runs().iter().map(|(point, _)| point).collect();
+    assert_eq!(points, vec!["1", "3"]);
+    assert_eq!(hanging.failures().len(), 1);
+    let failure = &hanging.failures()[0];
+    assert_eq!(failure.point(), Some(&"2".to_string()));
+    assert_eq!(failure.iteration(), 0);
+    assert_eq!(failure.kind(), FailureKind::Timeout);
+    Ok(())
+}
+
+#[test]
+fn test_hung_closure() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new_threaded("Test");
+    benchmarks.add_closure("hanging", hang_on_two, "hang", vec![1, 2, 3], 5, 0)?;
+    benchmarks.set_point_timeout("hanging", Duration::from_millis(100))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let hanging = &summary.series()["hanging"];
+    let points: Vec<&String> = hanging.runs().iter().map(|(point, _)| point).collect();
+    assert_eq!(points, vec!["1"]);
+    let failures: Vec<(Option<&String>, FailureKind)> = hanging
+        .failures()
+        .iter()
+        .map(|failure| (failure.point(), failure.kind()))
+        .collect();
+    assert_eq!(
+        failures,
+        vec![
+            (Some(&"2".to_string()), FailureKind::Timeout),
+            (None, FailureKind::Timeout),
+        ]
+    );
+    assert!(hanging.failures()[1]
+        .message()
+        .contains("1 workload points were not run"));
+    Ok(())
+}
+
+#[test]
+fn test_slow_closure() -> Result<(), anyhow::Error> {
+    let running = Arc::new(AtomicBool::new(false));
+    let teardowns = Arc::new(AtomicUsize::new(0));
+    let (captured_running, captured_teardowns) = (running.clone(), teardowns.clone());
+    let mut benchmarks = Benchmarks::new_threaded("Test");
+    benchmarks.add_closure(
+        "slow",
+        move |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            captured_running.store(true, Ordering::SeqCst);
+            if work == 2 {
+                thread::sleep(Duration::from_millis(150));
+            }
+            captured_running.store(false, Ordering::SeqCst);
+            Ok::<(), anyhow::Error>(())
+        },
+        "slow",
+        vec![1, 2, 3],
+        5,
+        0,
+    )?;
+    benchmarks.set_point_timeout("slow", Duration::from_millis(100))?;
+    // the teardown never overlaps an abandoned invocation
+    benchmarks.set_point_teardown("slow", move |_config: &&str, _work: &u64| {
+        assert!(!running.load(Ordering::SeqCst));
+        captured_teardowns.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    })?;
+    benchmarks.run()?;
+
+    // the closure is given back when the abandoned invocation returns
+    let summary = benchmarks.summary();
+    let slow = &summary.series()["slow"];
+    let points: Vec<&String> = slow.runs().iter().map(|(point, _)| point).collect();
+    assert_eq!(points, vec!["1", "3"]);
+    assert_eq!(slow.failures().len(), 1);
+    assert_eq!(slow.failures()[0].point(), Some(&"2".to_string()));
+    assert_eq!(slow.failures()[0].kind(), FailureKind::Timeout);
+    assert_eq!(teardowns.load(Ordering::SeqCst), 10);
+    Ok(())
+}
+
+#[test]
+fn test_watched_panic() -> Result<(), anyhow::Error> {
+    let mut benchmarks = Benchmarks::new_threaded("Test");
+    benchmarks.add_closure(
+        "panicking",
+        |_stop_watch: &mut StopWatch, _config: &str, work: u64| {
+            assert_ne!(work, 2);
+            Ok::<(), anyhow::Error>(())
+        },
+        "panic",
+        vec![1, 2, 3],
+        5,
+        0,
+    )?;
+    benchmarks.set_iteration_timeout("panicking", Duration::from_secs(10))?;
+    benchmarks.run()?;
+
+    let summary = benchmarks.summary();
+    let panicking = &summary.series()["panicking"];
+    assert_eq!(panicking.runs().len(), 2);
+    assert_eq!(panicking.failures().len(), 1);
+    assert_eq!(panicking.failures()[0].kind(), FailureKind::Panic);
+    Ok(())
+}